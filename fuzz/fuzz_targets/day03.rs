@@ -0,0 +1,7 @@
+#![no_main]
+
+use adv_code_2024::days::day03::Day03;
+use adv_code_2024::fuzzing;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| fuzzing::solve::<Day03>(input));
@@ -0,0 +1,165 @@
+use crate::grid::Grid;
+use crate::render::{Glyph, Render};
+use anyhow::*;
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+const HELP: &str = "Enter pause/step, c continue, + faster, - slower, q skip to the answer";
+
+/// Where the frames of an animated solution go: the terminal, or image files.
+pub trait Stage {
+    /// Shows one frame, a grid already drawn as glyphs, with a line describing it.
+    fn frame(&mut self, picture: &Grid<Glyph>, caption: &str) -> Result<()>;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimateConfig {
+    /// Frames per second while playing.
+    pub fps: f64,
+    /// Start paused, showing one frame per Enter.
+    pub paused: bool,
+}
+
+impl Default for AnimateConfig {
+    fn default() -> Self {
+        Self { fps: 20.0, paused: false }
+    }
+}
+
+/// Plays frames in the terminal, redrawing the screen for each.
+///
+/// Keys are read a line at a time, so every command is followed by Enter: an empty line pauses
+/// a playing animation and steps a paused one. Skipping stops drawing but lets the solver finish.
+pub struct Animation {
+    out: Box<dyn Write>,
+    keys: Receiver<String>,
+    fps: f64,
+    paused: bool,
+    skipping: bool,
+    frames: usize,
+}
+
+impl Animation {
+    pub fn new(out: Box<dyn Write>, keys: Receiver<String>, config: &AnimateConfig) -> Animation {
+        Self {
+            out,
+            keys,
+            fps: config.fps,
+            paused: config.paused,
+            skipping: false,
+            frames: 0,
+        }
+    }
+
+    /// An animation on stdout, controlled from stdin.
+    pub fn terminal(config: &AnimateConfig) -> Animation {
+        let (send, keys) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines().map_while(Result::ok) {
+                if send.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Self::new(Box::new(io::stdout()), keys, config)
+    }
+
+    /// Number of frames drawn so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Blocks until a key steps, continues or skips. A closed stdin continues.
+    fn wait(&mut self) {
+        while self.paused && !self.skipping {
+            match self.keys.recv() {
+                Result::Ok(key) if key.trim().is_empty() => return,
+                Result::Ok(key) => self.command(&key),
+                Err(_) => self.paused = false,
+            }
+        }
+    }
+
+    fn command(&mut self, key: &str) {
+        match key.trim() {
+            "" => self.paused = true,
+            "c" => self.paused = false,
+            "q" => self.skipping = true,
+            "+" => self.fps *= 2.0,
+            "-" => self.fps /= 2.0,
+            _ => (),
+        }
+    }
+}
+
+impl Stage for Animation {
+    /// Draws `picture` with a `caption` under it, then waits for the next frame to be due.
+    fn frame(&mut self, picture: &Grid<Glyph>, caption: &str) -> Result<()> {
+        if self.skipping {
+            return Ok(());
+        }
+        self.frames += 1;
+        write!(
+            self.out,
+            "\x1b[H\x1b[2J{}\n{}\nframe {} at {} fps; {}\n",
+            Render::new(picture, |glyph| *glyph),
+            caption,
+            self.frames,
+            self.fps,
+            HELP
+        )?;
+        self.out.flush()?;
+        if self.paused {
+            self.wait();
+        } else {
+            thread::sleep(Duration::from_secs_f64(1.0 / self.fps));
+            while let Result::Ok(key) = self.keys.try_recv() {
+                self.command(&key)
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture(n: u32) -> Grid<Glyph> {
+        Grid::from_rows(vec![vec![Glyph::new(char::from_digit(n, 10).unwrap())]]).unwrap()
+    }
+
+    fn animation(paused: bool, keys: &[&str]) -> Animation {
+        let (send, recv) = mpsc::channel();
+        for key in keys {
+            send.send(key.to_string()).unwrap();
+        }
+        let config = AnimateConfig { fps: 1000.0, paused };
+        Animation::new(Box::new(io::sink()), recv, &config)
+    }
+
+    #[test]
+    fn step_then_skip() -> Result<()> {
+        let mut animation = animation(true, &["", "+", "q"]);
+        for n in 0..5 {
+            animation.frame(&picture(n), "counting")?;
+        }
+        assert_eq!(2, animation.frames());
+        assert_eq!(2000.0, animation.fps);
+        Ok(())
+    }
+
+    #[test]
+    fn closed_input_plays_on() -> Result<()> {
+        let mut animation = animation(true, &["c", ""]);
+        for n in 0..4 {
+            animation.frame(&picture(n), "counting")?;
+        }
+        // `c` resumes after the first frame, the empty line pauses again and stdin is gone.
+        assert_eq!(4, animation.frames());
+        assert!(!animation.paused);
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use num_bigint::BigInt;
+use std::fmt::{self, Display, Formatter};
+
+/// The answer to one part, whatever type the day computes it in.
+///
+/// Integers are kept in the narrowest variant that holds them, so the same number compares equal
+/// however it was produced.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Every integer that fits an `i128`, which is all of them short of huge `u128`s.
+    Int(i128),
+    /// Integers past `i128`.
+    Big(BigInt),
+    /// Comma joined lists, passwords and the like.
+    Text(String),
+}
+
+/// Honours width and alignment, for tables.
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => n.fmt(f),
+            Answer::Big(n) => n.fmt(f),
+            Answer::Text(text) => f.pad(text),
+        }
+    }
+}
+
+/// Compares with an answer as stored in `answers/NN.toml` or an example's expectations.
+impl PartialEq<str> for Answer {
+    fn eq(&self, stored: &str) -> bool {
+        match self {
+            Answer::Int(n) => n.to_string() == stored,
+            Answer::Big(n) => n.to_string() == stored,
+            Answer::Text(text) => text == stored,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, stored: &&str) -> bool {
+        *self == **stored
+    }
+}
+
+impl PartialEq<Answer> for str {
+    fn eq(&self, answer: &Answer) -> bool {
+        *answer == *self
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, answer: &Answer) -> bool {
+        *answer == **self
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Int(n.into())
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Answer {
+        Answer::Int(n)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::Big(n.into()),
+        }
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Answer {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Answer {
+        match i128::try_from(&n) {
+            Ok(small) => Answer::Int(small),
+            Err(_) => Answer::Big(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_compare_however_they_were_computed() {
+        assert_eq!(Answer::from(41u8), Answer::from(41usize));
+        assert_eq!(Answer::from(-3i32), Answer::from(BigInt::from(-3)));
+        assert_eq!(Answer::Int(i128::MAX), Answer::from(i128::MAX as u128));
+        let big = Answer::from(u128::MAX);
+        assert!(matches!(big, Answer::Big(_)));
+        assert_eq!(big, Answer::from(BigInt::from(u128::MAX)));
+        assert_eq!(big, "340282366920938463463374607431768211455");
+    }
+
+    #[test]
+    fn compares_with_the_stored_answer() {
+        assert_eq!(Answer::from(1793usize), "1793");
+        assert_ne!(Answer::from(1793usize), "01793");
+        assert_eq!(Answer::from("7,2,1"), "7,2,1");
+        assert_eq!("-5", Answer::from(-5i64).to_string());
+        assert_eq!("|   42|ab   |", format!("|{:>5}|{:<5}|", Answer::from(42u8), Answer::from("ab")));
+    }
+}
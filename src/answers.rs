@@ -0,0 +1,135 @@
+use crate::runner::Part;
+use crate::Answer;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+
+pub const ANSWERS_DIR: &str = "answers";
+
+/// Confirmed answers of a single day, as kept in `answers/NN.toml`.
+///
+/// Answers are stored as their `Display` form, so an [`Answer`] of any kind round trips unchanged.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Match,
+    Mismatch { expected: String },
+    New,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Match => write!(f, "MATCH"),
+            Status::Mismatch { expected } => write!(f, "MISMATCH (expected {})", expected),
+            Status::New => write!(f, "NEW"),
+        }
+    }
+}
+
+/// Directory of per day answer files, used to catch regressions on the real inputs.
+pub struct AnswerStore {
+    dir: PathBuf,
+}
+
+impl Default for AnswerStore {
+    fn default() -> Self {
+        Self::new(ANSWERS_DIR)
+    }
+}
+
+impl AnswerStore {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> AnswerStore {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, day: &str) -> PathBuf {
+        self.dir.join(format!("{}.toml", day))
+    }
+
+    /// Answers recorded for `day`, empty if nothing has been confirmed yet.
+    pub fn load(&self, day: &str) -> Result<Answers> {
+        let path = self.path(day);
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let text = fs::read_to_string(&path)?;
+        toml::from_str(&text).with_context(|| format!("malformed answer file {}", path.display()))
+    }
+
+    pub fn check(&self, day: &str, part: Part, answer: &Answer) -> Result<Status> {
+        let status = match self.load(day)?.get(part) {
+            None => Status::New,
+            Some(expected) if *answer == *expected => Status::Match,
+            Some(expected) => Status::Mismatch {
+                expected: expected.to_string(),
+            },
+        };
+        Ok(status)
+    }
+
+    /// Records `answer` as the confirmed one. An already confirmed, different answer is never
+    /// replaced; fix the file by hand if it really was wrong.
+    pub fn confirm(&self, day: &str, part: Part, answer: &Answer) -> Result<Status> {
+        let mut answers = self.load(day)?;
+        let status = self.check(day, part, answer)?;
+        if status == Status::New {
+            answers.set(part, answer.to_string());
+            fs::create_dir_all(&self.dir)?;
+            fs::write(self.path(day), toml::to_string(&answers)?)?;
+        }
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(name: &str) -> AnswerStore {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        AnswerStore::new(dir)
+    }
+
+    #[test]
+    fn confirm_and_check() -> Result<()> {
+        let store = store("confirm");
+        assert_eq!(Status::New, store.check("06", Part::Two, &Answer::from(1793usize))?);
+        assert_eq!(Status::New, store.confirm("06", Part::Two, &Answer::from(1793usize))?);
+        assert_eq!(Status::Match, store.check("06", Part::Two, &Answer::from(1793u32))?);
+        assert_eq!(Status::New, store.check("06", Part::One, &Answer::from(41usize))?);
+        assert_eq!(
+            Status::Mismatch { expected: "1793".to_string() },
+            store.confirm("06", Part::Two, &Answer::from(1794usize))?
+        );
+        assert_eq!(Some("1793"), store.load("06")?.get(Part::Two));
+        store.confirm("17", Part::One, &Answer::from("4,6,3,5,6,3,5,2,1,0"))?;
+        assert_eq!(Status::Match, store.check("17", Part::One, &Answer::from("4,6,3,5,6,3,5,2,1,0"))?);
+        fs::remove_dir_all(&store.dir)?;
+        Ok(())
+    }
+}
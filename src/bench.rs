@@ -0,0 +1,192 @@
+use crate::runner::{Part, Puzzle};
+use crate::Answer;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+pub const BENCH_DIR: &str = "bench";
+
+#[derive(Clone, Debug)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub runs: usize,
+    /// Slowdown of the median over the baseline, in percent, above which a part has regressed.
+    pub threshold: f64,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 20,
+            threshold: 10.0,
+        }
+    }
+}
+
+/// Timings of one part over all measured runs, in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub day: String,
+    pub part: u8,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(day: &str, part: Part, samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        let mut ns: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        ns.sort_by(f64::total_cmp);
+        let n = ns.len();
+        let median = if n.is_multiple_of(2) {
+            (ns[n / 2 - 1] + ns[n / 2]) / 2.0
+        } else {
+            ns[n / 2]
+        };
+        let mean = ns.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Self {
+            day: day.to_string(),
+            part: part.into(),
+            runs: n,
+            min_ns: ns[0] as u64,
+            median_ns: median as u64,
+            mean_ns: mean as u64,
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+
+    /// Change of the median relative to `baseline`, in percent; positive is slower.
+    pub fn change_over(&self, baseline: &Stats) -> f64 {
+        let base = baseline.median_ns.max(1) as f64;
+        (self.median_ns as f64 - base) / base * 100.0
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ns = Duration::from_nanos;
+        write!(
+            f,
+            "{} runs, min {:.2?}, median {:.2?}, mean {:.2?}, stddev {:.2?}",
+            self.runs,
+            ns(self.min_ns),
+            ns(self.median_ns),
+            ns(self.mean_ns),
+            ns(self.stddev_ns)
+        )
+    }
+}
+
+/// Solves `part` over the in-memory `input` `config.runs` times after `config.warmup` unmeasured
+/// runs. Each run includes parsing, the same as a normal run does.
+pub fn measure(
+    puzzle: &dyn Puzzle,
+    part: Part,
+    input: &[u8],
+    config: &BenchConfig,
+) -> Result<(Answer, Stats)> {
+    ensure!(config.runs > 0, "at least one measured run is needed");
+    let mut answer = None;
+    for _ in 0..config.warmup {
+        answer = Some(puzzle.solve(part, &mut &input[..])?);
+    }
+    let mut samples = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs {
+        let start = Instant::now();
+        answer = Some(puzzle.solve(part, &mut &input[..])?);
+        samples.push(start.elapsed());
+    }
+    let answer = answer.expect("at least one measured run");
+    Ok((answer, Stats::from_samples(puzzle.day(), part, &samples)))
+}
+
+/// Machine readable bench results, kept as `bench/latest.toml` and `bench/baseline.toml`.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    #[serde(default)]
+    pub result: Vec<Stats>,
+}
+
+impl Report {
+    pub fn latest_path() -> PathBuf {
+        Path::new(BENCH_DIR).join("latest.toml")
+    }
+
+    pub fn baseline_path() -> PathBuf {
+        Path::new(BENCH_DIR).join("baseline.toml")
+    }
+
+    /// Reads a report, empty if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Report> {
+        if !path.exists() {
+            return Ok(Report::default());
+        }
+        let text = fs::read_to_string(path)?;
+        toml::from_str(&text).with_context(|| format!("malformed bench report {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: &str, part: Part) -> Option<&Stats> {
+        let part: u8 = part.into();
+        self.result.iter().find(|s| s.day == day && s.part == part)
+    }
+
+    /// Adds `stats`, replacing an earlier result of the same part.
+    pub fn upsert(&mut self, stats: Stats) {
+        self.result.retain(|s| !(s.day == stats.day && s.part == stats.part));
+        self.result.push(stats);
+        self.result.sort_by(|a, b| (&a.day, a.part).cmp(&(&b.day, b.part)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarises_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples("05", Part::Two, &samples);
+        assert_eq!(2, stats.part);
+        assert_eq!(1_000_000, stats.min_ns);
+        assert_eq!(2_500_000, stats.median_ns);
+        assert_eq!(2_500_000, stats.mean_ns);
+        assert_eq!(1_290_994, stats.stddev_ns);
+    }
+
+    #[test]
+    fn report_round_trips_and_compares() -> Result<()> {
+        let ms = |m| [Duration::from_millis(m)];
+        let mut report = Report::default();
+        report.upsert(Stats::from_samples("06", Part::One, &ms(10)));
+        report.upsert(Stats::from_samples("05", Part::One, &ms(10)));
+        report.upsert(Stats::from_samples("06", Part::One, &ms(20)));
+        let report: Report = toml::from_str(&toml::to_string(&report)?)?;
+
+        assert_eq!(2, report.result.len());
+        assert_eq!("05", report.result[0].day);
+        let slower = Stats::from_samples("06", Part::One, &ms(25));
+        assert_eq!(25.0, slower.change_over(report.get("06", Part::One).unwrap()));
+        assert!(report.get("06", Part::Two).is_none());
+        Ok(())
+    }
+}
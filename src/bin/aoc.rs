@@ -0,0 +1,557 @@
+use adv_code_2024::animate::{AnimateConfig, Animation, Stage};
+use adv_code_2024::answers::{AnswerStore, Status};
+use adv_code_2024::bench::{self, BenchConfig, Report, Stats};
+use adv_code_2024::config::Config;
+use adv_code_2024::days::{find, REGISTRY};
+use adv_code_2024::fetch::InputProvider;
+use adv_code_2024::generators;
+use adv_code_2024::image::{Format, Recorder, Snapshot};
+use adv_code_2024::memory;
+use adv_code_2024::parallel::{self, Table};
+use adv_code_2024::submit::{self, History, Outcome, SubmitClient};
+use adv_code_2024::{fixtures, lint, scaffold};
+use adv_code_2024::runner::{normalize_day, InputSource, Part, Puzzle};
+use adv_code_2024::*;
+use anyhow::*;
+use code_timing_macros::time_snippet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day> [--part <1|2>] [--confirm] [--input <path>|-] [<bench options>|<show options>]
+    aoc run --all [--part <1|2>] [--confirm] [<bench options>]
+    aoc run --all --parallel [--threads <n>] [--part <1|2>] [--confirm]
+    aoc fetch <day>|--all
+    aoc submit <day> <part>
+    aoc examples <day>|--all
+    aoc lint <day>|--all
+    aoc generate <day> [--seed <n>] [--size <n>] [--output <path>]
+    aoc new <day>
+    aoc list
+
+Missing inputs are downloaded to `input/NN.txt` with the session token from `AOC_SESSION`
+or `session` in `aoc.toml`; `AOC_BASE_URL` or `base_url` there points at another server.
+
+`--input` (or `AOC_INPUT`) solves another input instead, `-` reads it from stdin.
+
+`lint` checks `input/NN.txt` against the shape of the day's input: line endings, blank lines,
+unknown grid glyphs, ragged rows, malformed lines, and another day's input saved by mistake.
+
+`generate` makes up a random input of about `--size` lines or cells across (default 100) from
+`--seed` (default 0) and prints it; the same seed and size always give the same input.
+`--output` saves it instead, with the answers known from generating it next to it in `.toml`,
+so `--output examples/05/random.txt` adds an example case.
+
+A run of a single day reports the peak resident memory of each part (on Linux). Built with
+`--features count-allocations`, it also reports the allocations, bytes allocated and peak heap bytes.
+
+Every answer is checked against `answers/NN.toml`, `--confirm` records the new ones there.
+Answers and bench results of an input given with `--input` are neither checked nor saved.
+
+`--parallel` solves the days on a pool of threads (`--threads`, one per core by default) and
+prints a single table of answers, times and checks.
+
+Bench options:
+    --bench               time each part over many runs instead of once
+    --runs <n>            measured runs per part (default 20)
+    --warmup <n>          unmeasured runs before measuring (default 3)
+    --threshold <pct>     median slowdown over the baseline that counts as a regression (default 10)
+    --save-baseline       also store the results as the new baseline
+
+Results are written to `bench/latest.toml` and compared with `bench/baseline.toml`.
+
+Show options (days 06 and 08):
+    --animate             play the solution frame by frame in the terminal
+    --fps <n>             frames per second (default 20)
+    --step                start paused; Enter shows the next frame, c plays on, q skips to the answer
+    --frames <dir>        save every frame as an image `<dir>/NN-<part>-00001.png` instead
+    --format <png|ppm|svg>    image format of the frames (default png), needs --frames
+    --image <path>        save the final state as an image, `-<part>` is added when running both parts
+    --scale <px>          pixels per cell of saved images (default 4)
+";
+
+/// How to show a solution while it runs.
+enum Show {
+    Terminal(AnimateConfig),
+    Frames { dir: PathBuf, format: Format, scale: usize },
+    Image { path: PathBuf, scale: usize },
+}
+
+struct RunOptions {
+    parts: Vec<Part>,
+    source: InputSource,
+    confirm: bool,
+    bench: Option<BenchConfig>,
+    save_baseline: bool,
+    show: Option<Show>,
+}
+
+/// Problems found while running, reported once every requested day has run.
+#[derive(Default)]
+struct Tally {
+    mismatches: usize,
+    regressions: usize,
+}
+
+/// Solves one part through its animation, sending the frames where `show` says.
+fn show_part(
+    puzzle: &dyn Puzzle,
+    part: Part,
+    input: &[u8],
+    show: &Show,
+    options: &RunOptions,
+    animation: &mut Option<Animation>,
+) -> Result<Answer> {
+    match show {
+        Show::Terminal(config) => {
+            let animation: &mut dyn Stage = animation.get_or_insert_with(|| Animation::terminal(config));
+            puzzle.animate(part, &mut &input[..], animation)
+        }
+        Show::Frames { dir, format, scale } => {
+            let prefix = format!("{}-{}-", puzzle.day(), part);
+            let mut recorder = Recorder::new(dir, &prefix, *format, *scale)?;
+            let result = puzzle.animate(part, &mut &input[..], &mut recorder)?;
+            println!("Frames = {} in {}", recorder.frames(), dir.display());
+            Ok(result)
+        }
+        Show::Image { path, scale } => {
+            let mut snapshot = Snapshot::default();
+            let result = puzzle.animate(part, &mut &input[..], &mut snapshot)?;
+            let path = match (options.parts.len(), path.file_stem(), path.extension()) {
+                (1, _, _) => path.clone(),
+                (_, Some(stem), Some(extension)) => path.with_file_name(format!(
+                    "{}-{}.{}",
+                    stem.to_string_lossy(),
+                    part,
+                    extension.to_string_lossy()
+                )),
+                _ => bail!("{} has no image extension", path.display()),
+            };
+            snapshot.save(*scale, &path)?;
+            println!("Image = {}", path.display());
+            Ok(result)
+        }
+    }
+}
+
+/// Benchmarks one part, records it in the latest report and compares it with the baseline.
+fn bench_part(
+    puzzle: &dyn Puzzle,
+    part: Part,
+    input: &[u8],
+    config: &BenchConfig,
+    tally: &mut Tally,
+) -> Result<(Answer, Stats)> {
+    let (result, stats) = bench::measure(puzzle, part, input, config)?;
+    println!("Bench = {}", stats);
+    if let Some(baseline) = Report::load(&Report::baseline_path())?.get(puzzle.day(), part) {
+        let change = stats.change_over(baseline);
+        let verdict = if change > config.threshold {
+            tally.regressions += 1;
+            "REGRESSION"
+        } else {
+            "ok"
+        };
+        println!(
+            "Baseline = {:.2?} median, {:+.1}% {}",
+            Duration::from_nanos(baseline.median_ns),
+            change,
+            verdict
+        );
+    }
+    Ok((result, stats))
+}
+
+fn run_day(
+    puzzle: &dyn Puzzle,
+    options: &RunOptions,
+    inputs: &InputProvider,
+    store: &AnswerStore,
+    tally: &mut Tally,
+) -> Result<()> {
+    start_day(puzzle.day());
+    let input = options.source.read(puzzle.day(), inputs)?;
+    let mut latest = Report::load(&Report::latest_path())?;
+    let mut baseline = Report::load(&Report::baseline_path())?;
+    let mut animation = None;
+    for (n, &part) in options.parts.iter().enumerate() {
+        if n > 0 {
+            println!();
+        }
+        println!("=== Part {} ===", part);
+        let result = match (&options.bench, &options.show) {
+            (_, Some(show)) => show_part(puzzle, part, &input, show, options, &mut animation)?,
+            (Some(config), _) if !options.source.is_default() => {
+                let (result, stats) = bench::measure(puzzle, part, &input, config)?;
+                println!("Bench = {}", stats);
+                result
+            }
+            (Some(config), _) => {
+                let (result, stats) = bench_part(puzzle, part, &input, config, tally)?;
+                latest.upsert(stats.clone());
+                if options.save_baseline {
+                    baseline.upsert(stats);
+                }
+                result
+            }
+            (None, None) => {
+                let ((result, usage), resident) = memory::peak_resident(|| {
+                    memory::measure(|| time_snippet!(puzzle.solve(part, &mut &input[..])))
+                });
+                if let Some(peak) = resident {
+                    println!("Peak resident = {}", memory::Bytes(peak));
+                }
+                if let Some(usage) = usage {
+                    println!("Memory = {}", usage);
+                }
+                result?
+            }
+        };
+        println!("Result = {}", result);
+        if !options.source.is_default() {
+            continue;
+        }
+        let status = if options.confirm {
+            store.confirm(puzzle.day(), part, &result)?
+        } else {
+            store.check(puzzle.day(), part, &result)?
+        };
+        if let Status::Mismatch { .. } = status {
+            tally.mismatches += 1;
+        }
+        println!("Check = {}", status);
+    }
+    if options.bench.is_some() && options.source.is_default() {
+        latest.save(&Report::latest_path())?;
+        if options.save_baseline {
+            baseline.save(&Report::baseline_path())?;
+        }
+    }
+    Ok(())
+}
+
+/// Solves every day on `threads` threads and reports them all in one table.
+fn run_parallel(
+    options: &RunOptions,
+    threads: usize,
+    inputs: &InputProvider,
+    store: &AnswerStore,
+) -> Result<()> {
+    let start = Instant::now();
+    let rows = parallel::run_all(REGISTRY, &options.parts, threads, inputs, store, options.confirm);
+    let elapsed = start.elapsed();
+    print!("{}", Table(&rows));
+    println!(
+        "{} day(s), {} part(s) in {:.2?} on {} thread(s)",
+        REGISTRY.len(),
+        rows.len(),
+        elapsed,
+        threads
+    );
+    let failures = rows.iter().filter(|row| row.is_failure()).count();
+    ensure!(failures == 0, "{} part(s) failed or do not match the answer store", failures);
+    Ok(())
+}
+
+fn check_examples(puzzle: &dyn Puzzle) -> Result<usize> {
+    for case in fixtures::load(puzzle.day())?.iter().filter(|case| case.is_pending()) {
+        println!("Day {} {}: no expected answers yet", puzzle.day(), case.name);
+    }
+    let mut failures = 0;
+    for outcome in fixtures::run(puzzle)? {
+        let verdict = match &outcome.actual {
+            _ if outcome.passed() => "ok".to_string(),
+            Result::Ok(actual) => format!("FAILED (expected {}, got {})", outcome.expected, actual),
+            Err(e) => format!("FAILED ({:#})", e),
+        };
+        if !outcome.passed() {
+            failures += 1;
+        }
+        println!("Day {} {} part {}: {}", puzzle.day(), outcome.case, outcome.part, verdict);
+    }
+    Ok(failures)
+}
+
+/// The days named by a lone `<day>` or `--all` argument.
+fn select(args: &[String]) -> Result<Vec<&'static dyn Puzzle>> {
+    match args {
+        [all] if all == "--all" => Ok(REGISTRY.to_vec()),
+        [day] => {
+            let day = normalize_day(day)?;
+            Ok(vec![find(&day).with_context(|| format!("day {} is not solved yet", day))?])
+        }
+        _ => bail!("{}", USAGE),
+    }
+}
+
+fn fetch(args: &[String]) -> Result<()> {
+    let inputs = InputProvider::new(&Config::load()?);
+    for puzzle in select(args)? {
+        let cached = inputs.is_cached(puzzle.day());
+        let path = inputs.get(puzzle.day())?;
+        println!("{} {}", if cached { "Cached" } else { "Fetched" }, path.display());
+    }
+    Ok(())
+}
+
+fn submit(args: &[String]) -> Result<()> {
+    let [day, part] = args else { bail!("{}", USAGE) };
+    let (day, part) = (normalize_day(day)?, Part::parse(part)?);
+    let puzzle = find(&day).with_context(|| format!("day {} is not solved yet", day))?;
+    let config = Config::load()?;
+    let client = SubmitClient::new(&config)?;
+    let input = fs::read(InputProvider::new(&config).get(&day)?)?;
+
+    start_day(&day);
+    println!("=== Part {} ===", part);
+    let result = puzzle.solve(part, &mut &input[..])?;
+    println!("Result = {}", result);
+
+    let history_path = History::default_path();
+    let mut history = History::load(&history_path)?;
+    let answer = result.to_string();
+    history.check(&day, part, &answer, submit::now())?;
+    let outcome = client.post(&day, part, &answer)?;
+    history.record(&day, part, &answer, outcome.clone(), submit::now());
+    history.save(&history_path)?;
+    println!("Submitted = {}", outcome);
+    if outcome == Outcome::Right {
+        AnswerStore::default().confirm(&day, part, &result)?;
+    }
+    Ok(())
+}
+
+fn examples(args: &[String]) -> Result<()> {
+    let puzzles = select(args)?;
+    let mut failures = 0;
+    for puzzle in puzzles {
+        failures += check_examples(puzzle)?;
+    }
+    ensure!(failures == 0, "{} example(s) failed", failures);
+    Ok(())
+}
+
+fn lint(args: &[String]) -> Result<()> {
+    let inputs = InputProvider::new(&Config::load()?);
+    let mut problems = 0;
+    for puzzle in select(args)? {
+        let path = inputs.path(puzzle.day());
+        let input = match fs::read(&path) {
+            Result::Ok(input) => input,
+            Err(e) => {
+                problems += 1;
+                println!("{}: cannot read: {}", path.display(), e);
+                continue;
+            }
+        };
+        let found = lint::lint(&puzzle.shape(), &input);
+        if found.is_empty() {
+            println!("{}: ok", path.display());
+            continue;
+        }
+        problems += found.len();
+        for problem in found {
+            println!("{}: {}", path.display(), problem);
+        }
+        let others = lint::other_days(puzzle.day(), &input, REGISTRY);
+        if !others.is_empty() {
+            println!("{}: looks like the input of day {}", path.display(), others.join(" or "));
+        }
+    }
+    ensure!(problems == 0, "{} problem(s) found", problems);
+    Ok(())
+}
+
+fn generate(args: &[String]) -> Result<()> {
+    let mut day = None;
+    let (mut seed, mut size, mut output) = (0, 100, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = parse_flag(arg, args.next())?,
+            "--size" => size = parse_flag(arg, args.next())?,
+            "--output" => output = Some(PathBuf::from(args.next().context("--output needs a path")?)),
+            _ if day.is_none() && !arg.starts_with('-') => day = Some(normalize_day(arg)?),
+            _ => bail!("unexpected argument `{}`\n\n{}", arg, USAGE),
+        }
+    }
+    let day = day.with_context(|| format!("give a day\n\n{}", USAGE))?;
+    let generated = generators::generate(&day, seed, size)?;
+    let Some(path) = output else {
+        print!("{}", generated.input);
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, &generated.input)?;
+    println!("Created {}", path.display());
+    if generated.answers.part1.is_some() || generated.answers.part2.is_some() {
+        let answers = path.with_extension("toml");
+        fs::write(&answers, toml::to_string(&generated.answers)?)?;
+        println!("Created {}", answers.display());
+    }
+    Ok(())
+}
+
+fn parse_flag<T: FromStr>(flag: &str, value: Option<&String>) -> Result<T> {
+    let value = value.with_context(|| format!("{} needs a value", flag))?;
+    value.parse().map_err(|_| anyhow!("invalid value `{}` for {}", value, flag))
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let mut day = None;
+    let mut all = false;
+    let mut input = None;
+    let mut options = RunOptions {
+        parts: Part::BOTH.to_vec(),
+        source: InputSource::Default,
+        confirm: false,
+        bench: None,
+        save_baseline: false,
+        show: None,
+    };
+    let mut config = BenchConfig::default();
+    let mut bench = false;
+    let mut animate = AnimateConfig::default();
+    let mut animated = false;
+    let mut frames = None;
+    let mut format = None;
+    let mut image = None;
+    let mut scale = 4;
+    let mut parallel = false;
+    let mut threads = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--confirm" => options.confirm = true,
+            "--part" => {
+                let part = args.next().context("--part needs a value")?;
+                options.parts = vec![Part::parse(part)?];
+            }
+            "--input" => input = Some(args.next().context("--input needs a path or -")?),
+            "--bench" => bench = true,
+            "--runs" => config.runs = parse_flag(arg, args.next())?,
+            "--warmup" => config.warmup = parse_flag(arg, args.next())?,
+            "--threshold" => config.threshold = parse_flag(arg, args.next())?,
+            "--save-baseline" => options.save_baseline = true,
+            "--animate" => animated = true,
+            "--fps" => animate.fps = parse_flag(arg, args.next())?,
+            "--step" => animate.paused = true,
+            "--frames" => frames = Some(PathBuf::from(args.next().context("--frames needs a directory")?)),
+            "--format" => format = Some(Format::parse(args.next().context("--format needs a value")?)?),
+            "--image" => image = Some(PathBuf::from(args.next().context("--image needs a path")?)),
+            "--scale" => scale = parse_flag(arg, args.next())?,
+            "--parallel" => parallel = true,
+            "--threads" => threads = Some(parse_flag(arg, args.next())?),
+            _ if day.is_none() && !arg.starts_with('-') => day = Some(normalize_day(arg)?),
+            _ => bail!("unexpected argument `{}`\n\n{}", arg, USAGE),
+        }
+    }
+    ensure!(bench || !options.save_baseline, "--save-baseline needs --bench");
+    ensure!(animate.fps > 0.0, "--fps must be positive");
+    ensure!(frames.is_some() || format.is_none(), "--format needs --frames");
+    options.show = match (animated, frames, image) {
+        (false, None, None) => None,
+        (true, None, None) => Some(Show::Terminal(animate)),
+        (false, Some(dir), None) => Some(Show::Frames { dir, format: format.unwrap_or(Format::Png), scale }),
+        (false, None, Some(path)) => {
+            Format::of(&path)?;
+            Some(Show::Image { path, scale })
+        }
+        _ => bail!("give only one of --animate, --frames and --image"),
+    };
+    ensure!(
+        options.show.is_none() || !(bench || all),
+        "--animate, --frames and --image work with a single day and without --bench"
+    );
+    options.source = InputSource::resolve(input.map(String::as_str));
+    ensure!(
+        options.source.is_default() || (!all && !options.confirm),
+        "a custom input works with a single day and cannot be confirmed"
+    );
+    if bench {
+        options.bench = Some(config);
+    }
+    ensure!(parallel || threads.is_none(), "--threads needs --parallel");
+    ensure!(
+        !parallel || (all && !bench && options.show.is_none()),
+        "--parallel works with --all only, and without --bench or show options"
+    );
+    let inputs = InputProvider::new(&Config::load()?);
+    let store = AnswerStore::default();
+    if parallel {
+        let threads = match threads {
+            Some(threads) => threads,
+            None => thread::available_parallelism().map_or(1, usize::from),
+        };
+        ensure!(threads > 0, "--threads must be positive");
+        return run_parallel(&options, threads, &inputs, &store);
+    }
+    let mut tally = Tally::default();
+    match (day, all) {
+        (Some(day), false) => {
+            let puzzle = find(&day).with_context(|| format!("day {} is not solved yet", day))?;
+            run_day(puzzle, &options, &inputs, &store, &mut tally)?
+        }
+        (None, true) => {
+            for (n, puzzle) in REGISTRY.iter().enumerate() {
+                if n > 0 {
+                    println!();
+                }
+                run_day(*puzzle, &options, &inputs, &store, &mut tally)?;
+            }
+        }
+        _ => bail!("give either a day or --all\n\n{}", USAGE),
+    };
+    ensure!(
+        tally.mismatches == 0,
+        "{} answer(s) do not match the answer store",
+        tally.mismatches
+    );
+    ensure!(
+        tally.regressions == 0,
+        "{} part(s) regressed against the bench baseline",
+        tally.regressions
+    );
+    Ok(())
+}
+
+fn new(args: &[String]) -> Result<()> {
+    let [day] = args else { bail!("{}", USAGE) };
+    let day = normalize_day(day)?;
+    for path in scaffold::create(Path::new("."), &day)? {
+        println!("Created {}", path.display());
+    }
+    println!("Register `day{0}::Day{0}` in src/days/mod.rs to run it", day);
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("examples") => examples(&args[1..]),
+        Some("lint") => lint(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("list") => {
+            for puzzle in REGISTRY {
+                println!("Day {}", puzzle.day());
+            }
+            Ok(())
+        }
+        _ => bail!("{}", USAGE),
+    }
+}
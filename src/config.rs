@@ -0,0 +1,49 @@
+use anyhow::*;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+/// Local settings for talking to the Advent of Code site, read from `aoc.toml` (kept out of git)
+/// with `AOC_SESSION` and `AOC_BASE_URL` taking precedence.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Config> {
+        let mut config = Self::from_file(Path::new(CONFIG_FILE))?;
+        if let Result::Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Result::Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Config> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let text = fs::read_to_string(path)?;
+        toml::from_str(&text).with_context(|| format!("malformed config {}", path.display()))
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/')
+    }
+
+    pub fn session(&self) -> Result<&str> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .context("no session token, set AOC_SESSION or `session` in aoc.toml")
+    }
+}
@@ -0,0 +1,25 @@
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+// TODO: Register the day in `src/days/mod.rs` and add its examples to `examples/NN/`
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: &'static str = "NN"; // TODO: Fill the day
+    type Input = usize;
+    type Answer = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(reader.lines().map_while(Result::ok).filter(|l| !l.is_empty()).count())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        // TODO: Solve Part 1 of the puzzle
+        Ok(*input)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<usize> {
+        Ok(0)
+    }
+}
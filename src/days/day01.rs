@@ -0,0 +1,68 @@
+use crate::input::{parse_int_rows, Columns};
+use crate::lint::Shape;
+use crate::Solution;
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: &'static str = "01";
+    const SHAPE: Shape = Shape::IntRows(Columns::Exactly(2));
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer = i32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let rows = parse_int_rows::<i32>(reader, Self::DAY, Columns::Exactly(2))?;
+        Ok(rows.into_iter().map(|row| (row[0], row[1])).unzip())
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        let (mut first, mut second) = input.clone();
+        first.sort_unstable();
+        second.sort_unstable();
+        first
+            .iter()
+            .zip(second.iter())
+            .try_fold(0i32, |acc, (a, b)| {
+                let distance = if a < b { b.checked_sub(*a) } else { a.checked_sub(*b) };
+                distance.and_then(|d| acc.checked_add(d))
+            })
+            .context("the total distance overflows")
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32> {
+        let (first, second) = input;
+        let freqs = second.iter().fold(HashMap::new(), |mut freqs, e| {
+            *freqs.entry(e).or_insert(0) += 1;
+            freqs
+        });
+        first
+            .iter()
+            .try_fold(0i32, |acc, e| {
+                match freqs.get(e) {
+                    Some(f) => {
+                        e.checked_mul(*f).and_then(|score| acc.checked_add(score))
+                    }
+                    None => Some(acc)
+                }
+            })
+            .context("the similarity score overflows")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    #[test]
+    fn malformed_line() {
+        let err = Day01::parse("3   4\n4   x3\n".as_bytes()).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((2, 5, "x3"), (err.line, err.column, err.text.as_str()));
+        let err = Day01::parse("3   4\n4\n".as_bytes()).unwrap_err();
+        assert_eq!(2, err.downcast::<ParseError>().unwrap().line);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::input::{parse_int_rows, Columns};
+use crate::lint::Shape;
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+enum Direction {
+    Incr,
+    Decr,
+}
+
+pub struct Day02;
+
+fn check_safety(report: &[i32]) -> bool {
+    let mut direction: Option<Direction> = None;
+    for win in report.windows(2) {
+        // Wide enough for any two levels.
+        let delta = win[1] as i64 - win[0] as i64;
+        if delta.abs() > 3 || delta.abs() == 0 {
+            return false
+        }
+        match delta.signum() {
+            -1 => match direction {
+                None => {
+                    direction = Some(Direction::Decr);
+                }
+                Some(Direction::Incr) => {
+                    return false
+                }
+                Some(Direction::Decr) => {}
+            }
+            1 => match direction {
+                None => {
+                    direction = Some(Direction::Incr)
+                }
+                Some(Direction::Incr) => {}
+                Some(Direction::Decr) => {
+                    return false
+                }
+            }
+            0 => {
+                return false
+            }
+            _ => panic!("invalid number")
+        }
+    }
+    true
+}
+
+impl Solution for Day02 {
+    const DAY: &'static str = "02";
+    const SHAPE: Shape = Shape::IntRows(Columns::AtLeast(1));
+    type Input = Vec<Vec<i32>>;
+    type Answer = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_int_rows(reader, Self::DAY, Columns::AtLeast(1))
+    }
+
+    fn part1(reports: &Self::Input) -> Result<usize> {
+        let answer = reports.iter().filter(|&report| {
+            check_safety(report)
+        }).count();
+        Ok(answer)
+    }
+
+    fn part2(reports: &Self::Input) -> Result<usize> {
+        let answer = reports.iter().filter(|report| {
+            if check_safety(report) {
+                true
+            } else {
+                for i in 0..report.len() {
+                    let mut new_report = (*report).clone();
+                    new_report.remove(i);
+                    if check_safety(&new_report) {
+                        return true
+                    }
+                }
+                false
+            }
+        }).count();
+        Ok(answer)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::input::parse_lines;
+use crate::Solution;
+use anyhow::*;
+use regex::Regex;
+use std::io::BufRead;
+
+enum Command {
+    Activate,
+    Deactivate,
+    Result(i32),
+}
+
+impl Command {
+    fn from(input: String) -> Command {
+        let re_inner = Regex::new("^mul\\(([0-9]{1,3}),([0-9]{1,3})\\)$").unwrap();
+        match input.as_str() {
+            "do()" => Command::Activate,
+            "don't()" => Command::Deactivate,
+            _ => {
+                let mut res = 0;
+                for (_, [a1, a2]) in re_inner
+                    .captures_iter(input.as_str())
+                    .map(|caps| caps.extract())
+                {
+                    res = a1.parse::<i32>().unwrap() * a2.parse::<i32>().unwrap()
+                }
+                Command::Result(res)
+            }
+        }
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: &'static str = "03";
+    /// The memory is kept as raw lines, each part scans it for its own instructions.
+    type Input = Vec<String>;
+    type Answer = i32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_lines(reader, Self::DAY)
+    }
+
+    fn part1(memory: &Self::Input) -> Result<i32> {
+        // `\d` would take any Unicode digit, which `parse` then refuses.
+        let re = Regex::new("mul\\(([0-9]{1,3}),([0-9]{1,3})\\)").unwrap();
+        memory
+            .iter()
+            .flat_map(|line| {
+                let mut parts = vec![];
+                for (_, [a1, a2]) in re.captures_iter(line.as_str()).map(|caps| caps.extract()) {
+                    parts.push(a1.parse::<i32>().unwrap() * a2.parse::<i32>().unwrap())
+                }
+                parts
+            })
+            .try_fold(0i32, |acc, product| acc.checked_add(product))
+            .context("the sum of the multiplications overflows")
+    }
+
+    fn part2(memory: &Self::Input) -> Result<i32> {
+        let re_outer = Regex::new("mul\\([0-9]{1,3},[0-9]{1,3}\\)|do\\(\\)|don't\\(\\)").unwrap();
+        let commands: Vec<String> = memory
+            .iter()
+            .flat_map(|line| {
+                let matches: Vec<String> = re_outer
+                    .find_iter(line) // Borrow the line
+                    .map(|m| m.as_str().to_string()) // Convert matches to owned Strings
+                    .collect();
+                matches.into_iter()
+            })
+            .collect();
+        let answer = commands.iter().map(|c| Command::from(c.to_string())).try_fold(
+            (0i32, true),
+            |(acc, cap), command| match command {
+                Command::Activate => Some((acc, true)),
+                Command::Deactivate => Some((acc, false)),
+                Command::Result(res) => {
+                    if cap {
+                        Some((acc.checked_add(res)?, true))
+                    } else {
+                        Some((acc, false))
+                    }
+                }
+            },
+        );
+        Ok(answer.context("the sum of the enabled multiplications overflows")?.0)
+    }
+}
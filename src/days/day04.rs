@@ -0,0 +1,114 @@
+use crate::grid::{Grid, Pos};
+use crate::lint::Shape;
+use crate::point::Direction;
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+#[derive(PartialEq)]
+pub enum Char {
+    X,
+    M,
+    A,
+    S,
+    None,
+}
+
+impl Char {
+    fn from(c: char) -> Char {
+        match c {
+            'X' => Char::X,
+            'M' => Char::M,
+            'A' => Char::A,
+            'S' => Char::S,
+            _ => Char::None,
+        }
+    }
+}
+
+fn explore_x(grid: &Grid<Char>, start: Pos, dir: Direction) -> usize {
+    let mut pos = start;
+    for target in [Char::M, Char::A, Char::S] {
+        match grid.step(pos, dir) {
+            Some(next) if grid[next] == target => pos = next,
+            _ => return 0,
+        }
+    }
+    1
+}
+
+fn explore_a(grid: &Grid<Char>, start: Pos) -> usize {
+    // a b
+    //  X
+    // d c
+    let corner = |dir| grid.step(start, dir).map(|pos| &grid[pos]);
+    let corners = (
+        corner(Direction::UpLeft),
+        corner(Direction::UpRight),
+        corner(Direction::DownRight),
+        corner(Direction::DownLeft),
+    );
+    let (a, b, c, d) = match corners {
+        (Some(a), Some(b), Some(c), Some(d)) => (a, b, c, d),
+        _ => return 0,
+    };
+    // All the corners are inside
+    match a {
+        Char::M => {
+            if *c != Char::S {
+                return 0;
+            }
+        }
+        Char::S => {
+            if *c != Char::M {
+                return 0;
+            }
+        }
+        _ => return 0,
+    }
+    match b {
+        Char::M => {
+            if *d != Char::S {
+                return 0;
+            }
+        }
+        Char::S => {
+            if *d != Char::M {
+                return 0;
+            }
+        }
+        _ => return 0,
+    }
+    1
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: &'static str = "04";
+    const SHAPE: Shape = Shape::Grid(|c| matches!(c, 'X' | 'M' | 'A' | 'S'));
+    type Input = Grid<Char>;
+    type Answer = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Grid::parse(reader, Self::DAY, |c| Some(Char::from(c)))
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let mut xmasses = 0;
+        for start in grid.positions(|c| *c == Char::X) {
+            for dir in Direction::ALL {
+                xmasses += explore_x(grid, start, dir)
+            }
+        }
+        Ok(xmasses)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        let mut x_masses = 0;
+        for start in grid.positions(|c| *c == Char::A) {
+            x_masses += explore_a(grid, start)
+        }
+        Ok(x_masses)
+    }
+}
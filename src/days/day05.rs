@@ -0,0 +1,177 @@
+use crate::input::sections;
+use crate::lint::Shape;
+use crate::parsers::{comma_separated, unsigned};
+use crate::Solution;
+use anyhow::*;
+use nom::character::complete::char;
+use nom::sequence::separated_pair;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+type Pair = (u32, u32);
+type Update = Vec<u32>;
+
+#[derive(Debug)]
+struct Rule {
+    indegree: u32,
+    constraints: Vec<u32>,
+}
+
+impl Rule {
+    fn from_constraint(constraint: u32) -> Rule {
+        Self {
+            indegree: 0,
+            constraints: vec![constraint],
+        }
+    }
+
+    fn starting_1() -> Rule {
+        Self {
+            indegree: 1,
+            constraints: vec![],
+        }
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Vec<Pair>, Vec<Update>)> {
+    let [rules, updates] = sections(reader, Day05::DAY)?;
+    let pair = separated_pair(unsigned, char('|'), unsigned);
+    let rules = rules.parse_each(pair, "expected a `page|page` rule")?;
+    let updates = updates.parse_each(comma_separated(unsigned), "expected comma separated pages")?;
+    Ok((rules, updates))
+}
+
+fn build_rules(pairs: &[Pair]) -> HashMap<u32, Rule> {
+    let mut page_rules = HashMap::new();
+    for &(a, b) in pairs {
+        page_rules
+            .entry(a)
+            .and_modify(|r: &mut Rule| r.constraints.push(b))
+            .or_insert(Rule::from_constraint(b));
+        page_rules
+            .entry(b)
+            .and_modify(|r| r.indegree += 1)
+            .or_insert(Rule::starting_1());
+    }
+    page_rules
+}
+
+/// The pages of `update` in an order that keeps every rule between them, taking the earliest
+/// page that is free to go next. `None` if the rules go round in a cycle, which no order keeps.
+fn reorder(update: &[u32], page_rules: &HashMap<u32, Rule>) -> Option<Update> {
+    let before = |a: u32, b: u32| page_rules.get(&a).is_some_and(|r| r.constraints.contains(&b));
+    let mut indegree = vec![0; update.len()];
+    for (i, &a) in update.iter().enumerate() {
+        for (j, &b) in update.iter().enumerate() {
+            if i != j && before(a, b) {
+                indegree[j] += 1;
+            }
+        }
+    }
+    let mut placed = vec![false; update.len()];
+    let mut ordered = Vec::with_capacity(update.len());
+    while ordered.len() < update.len() {
+        let next = (0..update.len()).find(|&i| !placed[i] && indegree[i] == 0)?;
+        placed[next] = true;
+        ordered.push(update[next]);
+        for (j, &b) in update.iter().enumerate() {
+            if !placed[j] && before(update[next], b) {
+                indegree[j] -= 1;
+            }
+        }
+    }
+    Some(ordered)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: &'static str = "05";
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines { pattern: r"^\d+\|\d+$", what: "a `page|page` rule" },
+        Shape::Lines { pattern: r"^\d+(,\d+)*$", what: "comma separated pages" },
+    ]);
+    type Input = (Vec<Pair>, Vec<Update>);
+    type Answer = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1((pairs, updates): &Self::Input) -> Result<u64> {
+        let mut page_rules = build_rules(pairs);
+        let ans: u64 = updates
+            .iter()
+            .filter(|update| {
+                let mut upto = vec![];
+                for u in update.iter() {
+                    let broken = page_rules
+                        .entry(*u)
+                        .or_insert(Rule::starting_1())
+                        .constraints
+                        .iter()
+                        .filter(|c| upto.contains(c))
+                        .count()
+                        > 0;
+                    if broken {
+                        return false;
+                    }
+                    upto.push(u);
+                }
+                true
+            })
+            .map(|u| u64::from(u[u.len() / 2]))
+            .sum();
+        Ok(ans)
+    }
+
+    fn part2((pairs, updates): &Self::Input) -> Result<u64> {
+        let mut page_rules = build_rules(pairs);
+        let bad: Vec<Update> = updates
+            .iter()
+            .filter(|update| {
+                let mut upto = vec![];
+                for u in update.iter() {
+                    let broken = page_rules
+                        .entry(*u)
+                        .or_insert(Rule::starting_1())
+                        .constraints
+                        .iter()
+                        .filter(|c| upto.contains(c))
+                        .count()
+                        > 0;
+                    if broken {
+                        return true;
+                    }
+                    upto.push(u);
+                }
+                false
+            })
+            .cloned()
+            .collect();
+        let mut ans = 0;
+        for update in bad {
+            let ordered = reorder(&update, &page_rules)
+                .with_context(|| format!("the rules order the pages of {:?} in a cycle", update))?;
+            ans += u64::from(ordered[ordered.len() / 2]);
+        }
+        Ok(ans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    #[test]
+    fn malformed_update() {
+        let err = Day05::parse("47|53\n\n75,47,x61\n".as_bytes()).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((3, 6, ",x61"), (err.line, err.column, err.text.as_str()));
+        let err = Day05::parse("47|53|61\n\n75\n".as_bytes()).unwrap_err();
+        assert_eq!(6, err.downcast::<ParseError>().unwrap().column);
+        let err = Day05::parse("47|53\n\n75,47\n\n61\n".as_bytes()).unwrap_err();
+        assert_eq!(5, err.downcast::<ParseError>().unwrap().line);
+    }
+}
@@ -0,0 +1,326 @@
+use crate::animate::Stage;
+use crate::grid::{Grid, Pos};
+use crate::lint::Shape;
+use crate::point::Direction;
+use crate::render::{Colour, Glyph, Render};
+use crate::runner::Part;
+use crate::ParseError;
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Cell {
+    Obstacle,
+    Empty,
+    Path(Direction),
+    Guard,
+    Change
+}
+
+impl Cell {
+    fn from(c: char) -> Option<Cell> {
+        match c {
+            '.' => Some(Cell::Empty),
+            '#' => Some(Cell::Obstacle),
+            '^' => Some(Cell::Guard),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Guard {
+    position: Pos,
+    dir: Direction,
+}
+
+impl Guard {
+    fn new(position: Pos) -> Guard {
+        Self {
+            position,
+            dir: Direction::Up,
+        }
+    }
+
+    fn next(&self, grid: &Grid<Cell>) -> Option<Pos> {
+        grid.step(self.position, self.dir)
+    }
+
+    fn turn(&mut self) {
+        self.dir = self.dir.turn_right()
+    }
+
+    /// Steps onto `next`, the cell [`Guard::next`] found in front of them.
+    fn step(&mut self, next: Pos) {
+        self.position = next
+    }
+}
+
+/// Where the guard starts, and the lab it patrols.
+pub type Lab = (Pos, Grid<Cell>);
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Lab> {
+    let grid = Grid::parse(reader, Day06::DAY, Cell::from)?;
+    let guards: Vec<Pos> = grid.positions(|c| *c == Cell::Guard).take(2).collect();
+    match guards[..] {
+        [guard] => Ok((guard, grid)),
+        [] => bail!(ParseError::new(Day06::DAY, grid.height() + 1, 1, "", "there is no guard in the lab")),
+        // Every cell is one character, so the column is the cell's.
+        [.., (i, j)] => bail!(ParseError::new(Day06::DAY, i + 1, j + 1, "^", "there is more than one guard")),
+    }
+}
+
+/// Every way the guard has faced on each cell, a bit per direction; coming back to a cell
+/// facing the same way means going round in a loop.
+struct Headings(Grid<u8>);
+
+impl Headings {
+    fn new<T>(grid: &Grid<T>) -> Headings {
+        Self(grid.map(|_| 0))
+    }
+
+    /// Records the guard where they are, false if they have been there facing that way before.
+    fn record(&mut self, guard: &Guard) -> bool {
+        let heading = 1 << guard.dir.eighths();
+        let seen = self.0[guard.position] & heading != 0;
+        self.0[guard.position] |= heading;
+        !seen
+    }
+}
+
+/// The lab after an obstruction sent the guard back onto their own path.
+struct Looped {
+    grid: Grid<Cell>,
+    obstruction: Pos,
+    at: Pos,
+}
+
+/// Places an obstruction on `obstruction`, right in front of the guard, and walks on, until the
+/// guard leaves the lab or faces the same way somewhere they have been before, i.e. goes round in
+/// a loop.
+fn attempt(mut grid: Grid<Cell>, mut guard: Guard, obstruction: Pos) -> Option<Looped> {
+    grid[obstruction] = Cell::Change;
+    // A path cell only remembers the first way it was walked, and a guard boxed in turns on the
+    // spot without stepping at all, so the headings of this walk are kept aside.
+    let mut headings = Headings::new(&grid);
+    loop {
+        if !headings.record(&guard) {
+            return Some(Looped { grid, obstruction, at: guard.position });
+        }
+        let next = guard.next(&grid);
+        match next {
+            None => return None,
+            Some(next) => match grid[next] {
+                Cell::Obstacle | Cell::Change => {
+                    guard.turn();
+                }
+                Cell::Guard => unreachable!("the one guard's cell is walked before the patrol starts"),
+                Cell::Path(dir) => {
+                    // we are crossing the path
+                    if dir == guard.dir {
+                        return Some(Looped { grid, obstruction, at: next });
+                    }
+                    guard.step(next);
+                }
+                Cell::Empty => {
+                    grid[next] = Cell::Path(guard.dir);
+                    guard.step(next);
+                },
+            },
+        }
+    }
+}
+
+/// A moment of the patrol worth a frame when it is animated.
+enum Scene<'a> {
+    /// The guard walking the lab, in part 2 about to try an obstruction at `candidate`.
+    Patrol {
+        grid: &'a Grid<Cell>,
+        guard: &'a Guard,
+        candidate: Option<Pos>,
+    },
+    Loop(&'a Looped),
+}
+
+/// Walks the guard out of the lab, counting the visited positions.
+fn walk<S>((start, grid): &Lab, mut show: S) -> Result<usize>
+where
+    S: FnMut(Scene) -> Result<()>,
+{
+    let (start, mut grid) = (*start, grid.clone());
+    let mut guard = Guard::new(start);
+    grid[start] = Cell::Path(Direction::Up);
+    let mut path = 1;
+    let mut headings = Headings::new(&grid);
+    loop {
+        show(Scene::Patrol { grid: &grid, guard: &guard, candidate: None })?;
+        ensure!(headings.record(&guard), "the guard walks in a loop and never leaves the lab");
+        let next = guard.next(&grid);
+        match next {
+            None => break,
+            Some(next) => match grid[next] {
+                Cell::Obstacle | Cell::Change => {
+                    guard.turn();
+                }
+                Cell::Guard => unreachable!("the one guard's cell is walked before the patrol starts"),
+                Cell::Path(_) => {
+                    guard.step(next);
+                }
+                Cell::Empty => {
+                    path += 1;
+                    grid[next] = Cell::Path(guard.dir);
+                    guard.step(next);
+                },
+            },
+        }
+    }
+    Ok(path)
+}
+
+/// Walks the guard out of the lab, counting the positions where an obstruction makes them loop.
+fn obstruct<S>((start, grid): &Lab, mut show: S) -> Result<usize>
+where
+    S: FnMut(Scene) -> Result<()>,
+{
+    // Navigate the route saving the direction of path, and try an obstruction on every cell right
+    // before the guard first walks it; one on a cell walked already would have stopped them there.
+    let (start, mut grid) = (*start, grid.clone());
+    let mut guard = Guard::new(start);
+    grid[start] = Cell::Path(Direction::Up);
+    let mut options = 0;
+    let mut headings = Headings::new(&grid);
+    // walk the path.
+    loop {
+        ensure!(headings.record(&guard), "the guard walks in a loop and never leaves the lab");
+        let next = guard.next(&grid);
+        match next {
+            None => break,
+            Some(next) => match grid[next] {
+                Cell::Obstacle | Cell::Change => {
+                    guard.turn();
+                }
+                Cell::Guard => unreachable!("the one guard's cell is walked before the patrol starts"),
+                Cell::Path(_) => {
+                    guard.step(next);
+                }
+                Cell::Empty => {
+                    show(Scene::Patrol { grid: &grid, guard: &guard, candidate: Some(next) })?;
+                    if let Some(looped) = attempt(grid.clone(), guard.clone(), next) {
+                        show(Scene::Loop(&looped))?;
+                        options += 1
+                    }
+                    grid[next] = Cell::Path(guard.dir);
+                    guard.step(next);
+                },
+            },
+        }
+        show(Scene::Patrol { grid: &grid, guard: &guard, candidate: None })?;
+    }
+    Ok(options)
+}
+
+const GUARD: Glyph = Glyph::new('^').fg(Colour::Green);
+const CHANGE: Glyph = Glyph::new('O').fg(Colour::Red);
+
+/// Draws the lab with the walked path, marking where the guard started and, if given, the
+/// candidate obstruction.
+pub fn render(grid: &Grid<Cell>, start: Pos, obstruction: Option<Pos>) -> Render<'_, Cell> {
+    let render = Render::new(grid, |cell| match cell {
+        Cell::Empty => Glyph::new('.').fg(Colour::Grey),
+        Cell::Obstacle => Glyph::new('#'),
+        // Up is yellow, then round the colour wheel with every turn.
+        Cell::Path(dir) => Glyph::new(dir.arrow()).fg(Colour::hue(60.0 + 45.0 * dir.eighths() as f64)),
+        Cell::Guard => GUARD,
+        Cell::Change => CHANGE,
+    })
+    .marker(start, GUARD);
+    match obstruction {
+        Some(pos) => render.marker(pos, CHANGE),
+        None => render,
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: &'static str = "06";
+    const SHAPE: Shape = Shape::Grid(|c| matches!(c, '.' | '#' | '^'));
+    type Input = Lab;
+    type Answer = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(lab: &Self::Input) -> Result<usize> {
+        walk(lab, |_| Ok(()))
+    }
+
+    fn part2(lab: &Self::Input) -> Result<usize> {
+        obstruct(lab, |_| Ok(()))
+    }
+
+    fn animate(lab: &Self::Input, part: Part, stage: &mut dyn Stage) -> Result<usize> {
+        let start = lab.0;
+        let show = |scene: Scene| match scene {
+            Scene::Patrol { grid, guard, candidate } => {
+                let at = Glyph::new(guard.dir.arrow()).fg(Colour::Black).bg(Colour::Green);
+                let picture = render(grid, start, candidate).marker(guard.position, at);
+                let caption = match candidate {
+                    Some(pos) => format!(
+                        "guard at {:?} heading {}, trying an obstruction at {:?}",
+                        guard.position,
+                        guard.dir.arrow(),
+                        pos
+                    ),
+                    None => format!("guard at {:?} heading {}", guard.position, guard.dir.arrow()),
+                };
+                stage.frame(&picture.glyphs(), &caption)
+            }
+            Scene::Loop(looped) => {
+                let picture =
+                    render(&looped.grid, start, Some(looped.obstruction)).highlight([looped.at], Colour::Red);
+                let caption = format!(
+                    "an obstruction at {:?} makes a loop, found at {:?}",
+                    looped.obstruction, looped.at
+                );
+                stage.frame(&picture.glyphs(), &caption)
+            }
+        };
+        match part {
+            Part::One => walk(lab, show),
+            Part::Two => obstruct(lab, show),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animate::{AnimateConfig, Animation};
+    use std::sync::mpsc;
+
+    #[test]
+    fn animation_gives_the_same_answers() -> Result<()> {
+        let lab = Day06::parse(&std::fs::read("examples/06/example.txt")?[..])?;
+        let (_, keys) = mpsc::channel();
+        let config = AnimateConfig { fps: 10_000.0, paused: false };
+        let mut animation = Animation::new(Box::new(std::io::sink()), keys, &config);
+        assert_eq!(Day06::part1(&lab)?, Day06::animate(&lab, Part::One, &mut animation)?);
+        let walked = animation.frames();
+        assert_eq!(Day06::part2(&lab)?, Day06::animate(&lab, Part::Two, &mut animation)?);
+        assert!(animation.frames() > walked);
+        Ok(())
+    }
+
+    #[test]
+    fn obstruction_before_the_first_turn() -> Result<()> {
+        // Found by the differential tests: an obstruction on the first leg boxes the guard in,
+        // so they turn on the spot for ever without stepping on their path again.
+        let lab = Day06::parse(&b"...\n#^#\n.#.\n...\n"[..])?;
+        assert_eq!(2, Day06::part1(&lab)?);
+        assert_eq!(1, Day06::part2(&lab)?);
+        Ok(())
+    }
+}
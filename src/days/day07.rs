@@ -0,0 +1,122 @@
+use crate::lint::Shape;
+use crate::parsers::{key_values, parse_all, unsigned};
+use crate::Solution;
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+type Calibration = (usize, Vec<usize>);
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Addition,
+    Multiplication,
+    Concatenation
+}
+
+impl Operator {
+    /// The left operand `a` that makes `a <op> b` come to `target`, if there is one. `Some(None)`
+    /// stands for any left operand at all, which is how multiplying by zero behaves.
+    fn undo(self, target: usize, b: usize) -> Option<Option<usize>> {
+        match self {
+            Operator::Addition => target.checked_sub(b).map(Some),
+            Operator::Multiplication if b == 0 => (target == 0).then_some(None),
+            Operator::Multiplication => target.is_multiple_of(b).then_some(Some(target / b)),
+            Operator::Concatenation => {
+                // Wide enough for the 20 digits of the largest operand.
+                let shift = 10u128.pow(b.to_string().len() as u32);
+                let target = target as u128;
+                (target % shift == b as u128).then_some(Some((target / shift) as usize))
+            }
+        }
+    }
+}
+
+/// Whether some `operators` between `operands`, evaluated left to right, make `target`. Works
+/// back from the last operand, so only operators that can have produced the target are tried.
+fn solvable(target: usize, operands: &[usize], operators: &[Operator]) -> bool {
+    search(target, operands, operators, &mut HashSet::new())
+}
+
+/// [`solvable`], remembering the `(operands, target)` pairs known to fail: operands like `1`
+/// undo every operator, and without them the search doubles with each one.
+fn search(
+    target: usize,
+    operands: &[usize],
+    operators: &[Operator],
+    dead: &mut HashSet<(usize, usize)>,
+) -> bool {
+    let found = match operands {
+        [] => false,
+        [first] => *first == target,
+        _ if dead.contains(&(operands.len(), target)) => false,
+        [rest @ .., last] => operators.iter().any(|op| match op.undo(target, *last) {
+            None => false,
+            Some(None) => true,
+            Some(Some(target)) => search(target, rest, operators, dead),
+        }),
+    };
+    if !found {
+        dead.insert((operands.len(), target));
+    }
+    found
+}
+
+fn total(calibrations: &[Calibration], operators: &[Operator]) -> Result<usize> {
+    calibrations
+        .iter()
+        .filter(|(target, operands)| solvable(*target, operands, operators))
+        .try_fold(0usize, |acc, c| acc.checked_add(c.0))
+        .context("the total calibration result overflows")
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Calibration>> {
+    let mut calibrations = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let calibration = key_values(unsigned, unsigned);
+        calibrations.push(parse_all(Day07::DAY, i, &line, calibration, "expected `target: operands`")?);
+    }
+    Ok(calibrations)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: &'static str = "07";
+    const SHAPE: Shape = Shape::Lines { pattern: r"^\d+: \d+( \d+)*$", what: "`target: operands`" };
+    type Input = Vec<Calibration>;
+    type Answer = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(calibrations: &Self::Input) -> Result<usize> {
+        total(calibrations, &[Operator::Addition, Operator::Multiplication])
+    }
+
+    fn part2(calibrations: &Self::Input) -> Result<usize> {
+        total(calibrations, &[Operator::Addition, Operator::Multiplication, Operator::Concatenation])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solvable() {
+        let all = [Operator::Addition, Operator::Multiplication, Operator::Concatenation];
+        assert!(solvable(3, &[1, 2], &all[..1]));
+        assert!(solvable(2, &[1, 2], &all[1..2]));
+        assert!(solvable(12, &[1, 2], &all[2..]));
+        assert!(!solvable(12, &[1, 2], &all[..2]));
+        assert!(solvable(1212344123212323, &[1212344123, 212323], &all[2..]));
+        // Multiplying by zero wipes out whatever came before it.
+        assert!(solvable(5, &[7, 3, 0, 5], &all[..2]));
+        assert!(!solvable(5, &[usize::MAX, usize::MAX, 5], &all));
+        // Twice the choices with every `1`, for nothing: 200 of them add up to 200 at most.
+        assert!(!solvable(1000, &[1; 200], &all[..2]));
+    }
+}
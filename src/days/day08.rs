@@ -0,0 +1,156 @@
+use crate::animate::Stage;
+use crate::grid::{Grid, Pos};
+use crate::lint::Shape;
+use crate::point::Point;
+use crate::render::{Colour, Glyph, Render};
+use crate::runner::Part;
+use crate::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+#[derive(Clone, PartialEq)]
+pub enum Plot {
+    Empty,
+    Antinode,
+    Node(char),
+}
+
+pub struct Antennae {
+    freq: char,
+    at: Point,
+}
+
+impl Antennae {
+    fn first_antinode(a1: &Antennae, a2: &Antennae, land: &Grid<Plot>) -> Vec<Pos> {
+        let d = a1.at - a2.at;
+        [a1.at + d, a2.at - d]
+            .into_iter()
+            .filter_map(|point| land.bounded(point))
+            .collect()
+    }
+
+    fn resonant_antinodes(a1: &Antennae, a2: &Antennae, land: &Grid<Plot>) -> Vec<Pos> {
+        let mut res = Vec::new();
+        for (from, d) in [(a1.at, a2.at - a1.at), (a2.at, a1.at - a2.at)] {
+            let mut node = land.bounded(from + d);
+            while let Some(pos) = node {
+                res.push(pos);
+                node = land.offset(pos, d);
+            }
+        }
+        res
+    }
+}
+
+impl Antennae {
+    fn new(ch: char, at: Pos) -> Antennae {
+        Self {
+            freq: ch,
+            at: Point::from(at),
+        }
+    }
+}
+
+/// Draws the antennae coloured by frequency, with `antinodes` over them.
+pub fn render<I: IntoIterator<Item = Pos>>(land: &Grid<Plot>, antinodes: I) -> Render<'_, Plot> {
+    let plot = |plot: &Plot| match *plot {
+        Plot::Empty => Glyph::new('.').fg(Colour::Grey),
+        Plot::Antinode => Glyph::new('#').fg(Colour::Red),
+        Plot::Node(f) => Glyph::new(f).fg(Colour::distinct(f as usize)),
+    };
+    let (over_empty, over_nodes): (Vec<Pos>, Vec<Pos>) =
+        antinodes.into_iter().partition(|&pos| land.get(pos) == Some(&Plot::Empty));
+    let render = Render::new(land, plot).highlight(over_nodes, Colour::Red);
+    over_empty
+        .into_iter()
+        .fold(render, |render, pos| render.marker(pos, plot(&Plot::Antinode)))
+}
+
+/// Antennae bucketed by their (ASCII) frequency.
+pub type Frequencies = [Vec<Antennae>; 256];
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Frequencies, Grid<Plot>)> {
+    let layofland = Grid::parse(reader, Day08::DAY, |ch| match ch {
+        '.' => Some(Plot::Empty),
+        // frequencies are bucketed by their ASCII code
+        _ if ch.is_ascii() => Some(Plot::Node(ch)),
+        _ => None,
+    })?;
+    let mut frequencies: Frequencies = std::array::from_fn(|_| Vec::new());
+    for (pos, plot) in layofland.cells() {
+        if let Plot::Node(ch) = *plot {
+            frequencies[ch as usize].push(Antennae::new(ch, pos));
+        }
+    }
+    Ok((frequencies, layofland))
+}
+
+type Antinodes = fn(&Antennae, &Antennae, &Grid<Plot>) -> Vec<Pos>;
+
+/// Marks every antinode produced by `antinodes` for each pair of same-frequency antennae,
+/// returning how many distinct plots got marked. `show` sees the land after every frequency.
+fn mark_antinodes<S>(
+    frequencies: &Frequencies,
+    layofland: &mut Grid<Plot>,
+    antinodes: Antinodes,
+    mut show: S,
+) -> Result<usize>
+where
+    S: FnMut(&Grid<Plot>, char) -> Result<()>,
+{
+    frequencies
+        .iter()
+        .filter(|&f| !f.is_empty())
+        .try_fold(0, |mut acc, freq| {
+            for i in 0..freq.len() {
+                for j in i + 1..freq.len() {
+                    for point in antinodes(&freq[i], &freq[j], layofland) {
+                        match layofland[point] {
+                            Plot::Empty | Plot::Node(_) => {
+                                layofland[point] = Plot::Antinode;
+                                acc += 1
+                            }
+                            _ => continue,
+                        }
+                    }
+                }
+            }
+            show(layofland, freq[0].freq)?;
+            Ok(acc)
+        })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: &'static str = "08";
+    const SHAPE: Shape = Shape::Grid(|c| c == '.' || c.is_ascii_alphanumeric());
+    type Input = (Frequencies, Grid<Plot>);
+    type Answer = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1((frequencies, layofland): &Self::Input) -> Result<usize> {
+        let mut layofland = layofland.clone();
+        mark_antinodes(frequencies, &mut layofland, Antennae::first_antinode, |_, _| Ok(()))
+    }
+
+    fn part2((frequencies, layofland): &Self::Input) -> Result<usize> {
+        let mut layofland = layofland.clone();
+        mark_antinodes(frequencies, &mut layofland, Antennae::resonant_antinodes, |_, _| Ok(()))
+    }
+
+    fn animate((frequencies, layofland): &Self::Input, part: Part, stage: &mut dyn Stage) -> Result<usize> {
+        let antinodes: Antinodes = match part {
+            Part::One => Antennae::first_antinode,
+            Part::Two => Antennae::resonant_antinodes,
+        };
+        let mut marked = layofland.clone();
+        mark_antinodes(frequencies, &mut marked, antinodes, |marked, freq| {
+            let picture = render(layofland, marked.positions(|p| *p == Plot::Antinode)).glyphs();
+            stage.frame(&picture, &format!("antinodes up to frequency `{}`", freq))
+        })
+    }
+}
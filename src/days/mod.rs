@@ -0,0 +1,29 @@
+//! Solutions for each day. `NN.rs` is the template for a new day and deliberately not a module.
+
+use crate::runner::{Puzzle, Registered};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+
+/// Every implemented day, in calendar order.
+pub static REGISTRY: &[&dyn Puzzle] = &[
+    &Registered::<day01::Day01>::new(),
+    &Registered::<day02::Day02>::new(),
+    &Registered::<day03::Day03>::new(),
+    &Registered::<day04::Day04>::new(),
+    &Registered::<day05::Day05>::new(),
+    &Registered::<day06::Day06>::new(),
+    &Registered::<day07::Day07>::new(),
+    &Registered::<day08::Day08>::new(),
+];
+
+/// Looks a day up by its zero padded number.
+pub fn find(day: &str) -> Option<&'static dyn Puzzle> {
+    REGISTRY.iter().find(|p| p.day() == day).copied()
+}
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+
+/// A malformed puzzle input, pointing at the offending spot so it can be fixed by hand.
+///
+/// Lines and columns are 1 based, columns count characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: &'static str,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new<S: Into<String>>(
+        day: &'static str,
+        line: usize,
+        column: usize,
+        text: &str,
+        reason: S,
+    ) -> ParseError {
+        Self {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Error for `fragment`, which must be a slice of `line`; its offset in there gives the
+    /// column. `line_idx` is the 0 based index as produced by `enumerate`.
+    pub fn at<S: Into<String>>(
+        day: &'static str,
+        line_idx: usize,
+        line: &str,
+        fragment: &str,
+        reason: S,
+    ) -> ParseError {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&offset| offset <= line.len() && line.is_char_boundary(offset))
+            .unwrap_or(0);
+        let column = line[..offset].chars().count() + 1;
+        Self::new(day, line_idx + 1, column, fragment, reason)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {} at `{}`",
+            self.day, self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_of_fragment() {
+        let line = "47|5x3";
+        let err = ParseError::at("05", 2, line, &line[4..], "expected a page number");
+        assert_eq!((3, 5), (err.line, err.column));
+        assert_eq!("x3", err.text);
+        assert_eq!(
+            "day 05, line 3, column 5: expected a page number at `x3`",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn column_counts_chars() {
+        let line = "↑→x";
+        let err = ParseError::at("06", 0, line, &line[6..], "unknown cell");
+        assert_eq!(3, err.column);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::config::{Config, YEAR};
+use anyhow::*;
+use std::fs;
+use std::path::PathBuf;
+
+/// Hands out puzzle inputs from `input/`, downloading the ones that are missing.
+///
+/// A cached input is never fetched again; delete the file to force a new download. An empty
+/// file, like the one `aoc new` leaves, does not count as cached.
+pub struct InputProvider {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+}
+
+impl InputProvider {
+    pub fn new(config: &Config) -> InputProvider {
+        Self {
+            base_url: config.base_url().to_string(),
+            session: config.session().ok().map(str::to_string),
+            cache_dir: PathBuf::from("input"),
+        }
+    }
+
+    pub fn with_cache_dir<P: Into<PathBuf>>(mut self, cache_dir: P) -> InputProvider {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    pub fn path(&self, day: &str) -> PathBuf {
+        self.cache_dir.join(format!("{}.txt", day))
+    }
+
+    /// Whether the input of `day` is in the cache and not empty.
+    pub fn is_cached(&self, day: &str) -> bool {
+        fs::metadata(self.path(day)).is_ok_and(|meta| meta.len() > 0)
+    }
+
+    /// Path of the input of `day`, fetching it first unless it is cached already.
+    pub fn get(&self, day: &str) -> Result<PathBuf> {
+        let path = self.path(day);
+        if !self.is_cached(day) {
+            let input = self
+                .fetch(day)
+                .with_context(|| format!("no input for day {} at {}", day, path.display()))?;
+            fs::create_dir_all(&self.cache_dir)?;
+            // Write aside and move in place, so a failed write never leaves a truncated input.
+            let partial = path.with_extension("part");
+            fs::write(&partial, input)?;
+            fs::rename(&partial, &path)?;
+        }
+        Ok(path)
+    }
+
+    fn fetch(&self, day: &str) -> Result<String> {
+        let session = self
+            .session
+            .as_deref()
+            .context("no session token to download it, set AOC_SESSION or `session` in aoc.toml")?;
+        let n: u8 = day.parse()?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, n);
+        let input = match ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+        {
+            Result::Ok(response) => response.into_string()?,
+            Err(ureq::Error::Status(code, _)) => {
+                bail!("{} answered {}, is the session token still valid?", url, code)
+            }
+            Err(e) => return Err(e).with_context(|| format!("cannot reach {}", url)),
+        };
+        ensure!(!input.is_empty(), "{} returned an empty input", url);
+        Ok(input)
+    }
+}
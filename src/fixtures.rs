@@ -0,0 +1,90 @@
+use crate::answers::Answers;
+use crate::runner::{Part, Puzzle};
+use crate::Answer;
+use anyhow::*;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// One example case: the input `examples/NN/<name>.txt` and its expected answers in
+/// `examples/NN/<name>.toml`. Either part may be left out of the expectations.
+#[derive(Debug)]
+pub struct Case {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: Answers,
+}
+
+impl Case {
+    /// Whether the case expects neither part yet, like the one `aoc new` scaffolds.
+    pub fn is_pending(&self) -> bool {
+        self.expected.part1.is_none() && self.expected.part2.is_none()
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub case: String,
+    pub part: Part,
+    pub expected: String,
+    pub actual: Result<Answer>,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        matches!(&self.actual, Result::Ok(actual) if *actual == *self.expected)
+    }
+}
+
+pub fn day_dir(day: &str) -> PathBuf {
+    Path::new(EXAMPLES_DIR).join(day)
+}
+
+/// All example cases of `day`, ordered by name. A day without an examples directory has none.
+pub fn load(day: &str) -> Result<Vec<Case>> {
+    load_dir(&day_dir(day))
+}
+
+/// All example cases in `dir`, ordered by name.
+pub fn load_dir(dir: &Path) -> Result<Vec<Case>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut cases = vec![];
+    for entry in fs::read_dir(dir)? {
+        let input = entry?.path();
+        if input.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let answers = input.with_extension("toml");
+        let text = fs::read_to_string(&answers)
+            .with_context(|| format!("{} has no expected answers", input.display()))?;
+        let expected: Answers = toml::from_str(&text)
+            .with_context(|| format!("malformed expected answers {}", answers.display()))?;
+        let name = input.file_stem().unwrap().to_string_lossy().into_owned();
+        cases.push(Case { name, input, expected });
+    }
+    cases.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(cases)
+}
+
+/// Runs every example case of `puzzle` for each part it has an expected answer for.
+pub fn run(puzzle: &dyn Puzzle) -> Result<Vec<Outcome>> {
+    let mut outcomes = vec![];
+    for case in load(puzzle.day())? {
+        for part in Part::BOTH {
+            if let Some(expected) = case.expected.get(part) {
+                let mut reader = BufReader::new(File::open(&case.input)?);
+                outcomes.push(Outcome {
+                    case: case.name.clone(),
+                    part,
+                    expected: expected.to_string(),
+                    actual: puzzle.solve(part, &mut reader),
+                });
+            }
+        }
+    }
+    Ok(outcomes)
+}
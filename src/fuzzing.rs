@@ -0,0 +1,21 @@
+//! The body of the fuzz targets in `fuzz/`, shared with the hostile input tests so a stable
+//! toolchain checks the same thing.
+use crate::{ParseError, Solution};
+
+/// Parses `input`, whatever bytes it holds, and answers both parts of it. Refusing the input or
+/// failing a part is fine; panicking or never returning is the bug, and so is refusing it with
+/// anything but a [`ParseError`] or the I/O error that invalid UTF-8 comes as.
+pub fn solve<S: Solution>(input: &[u8]) {
+    match S::parse(input) {
+        Ok(parsed) => {
+            let _ = S::part1(&parsed);
+            let _ = S::part2(&parsed);
+        }
+        Err(e) => assert!(
+            e.is::<ParseError>() || e.is::<std::io::Error>(),
+            "day {} refused its input with a bare error: {:#}",
+            S::DAY,
+            e
+        ),
+    }
+}
@@ -0,0 +1,395 @@
+//! Random but valid puzzle inputs, for testing and benchmarking beyond the examples.
+//!
+//! Everything is derived from a seed with a small generator of our own, so a seed and a size
+//! name the same input on every machine and every version of the dependencies. Where the
+//! construction decides the answers (days 02, 03 and 05) they come along with the input.
+use crate::answers::Answers;
+use anyhow::*;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// SplitMix64: tiny, fast and good enough to make up puzzle inputs.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`; `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        range.start() + self.next_u64() % (range.end() - range.start() + 1)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A generated input, and the answers its construction determines.
+#[derive(Debug)]
+pub struct Generated {
+    pub input: String,
+    pub answers: Answers,
+}
+
+impl Generated {
+    fn new(input: String) -> Generated {
+        Self {
+            input,
+            answers: Answers::default(),
+        }
+    }
+
+    fn answered(input: String, part1: impl ToString, part2: impl ToString) -> Generated {
+        let answers = Answers {
+            part1: Some(part1.to_string()),
+            part2: Some(part2.to_string()),
+        };
+        Self { input, answers }
+    }
+}
+
+/// An input for `day` from `seed`. `size` is about the number of lines, or the side of the grid,
+/// of the input; the real inputs are around 1000 lines and 50 to 140 cells wide.
+pub fn generate(day: &str, seed: u64, size: usize) -> Result<Generated> {
+    ensure!(size > 0, "the size of an input must be positive");
+    let rng = &mut Rng::new(seed);
+    let generated = match day {
+        "01" => day01(rng, size),
+        "02" => day02(rng, size),
+        "03" => day03(rng, size.div_ceil(200), size),
+        "04" => day04(rng, size, size),
+        "05" => day05(rng, size / 4, size),
+        "06" => day06(rng, size, size, 0.1),
+        "07" => day07(rng, size),
+        "08" => {
+            let frequencies = (size / 4).clamp(1, FREQUENCIES.len());
+            day08(rng, size, size, frequencies, (size * size / frequencies).min(4))?
+        }
+        _ => bail!("there is no input generator for day {}", day),
+    };
+    Ok(generated)
+}
+
+/// Two columns of location ids, the right one reusing about half of the left ones.
+pub fn day01(rng: &mut Rng, lines: usize) -> Generated {
+    let left: Vec<u64> = (0..lines).map(|_| rng.range(10_000..=99_999)).collect();
+    let mut input = String::new();
+    for &id in &left {
+        let right = if rng.chance(0.5) { *rng.pick(&left) } else { rng.range(10_000..=99_999) };
+        input += &format!("{}   {}\n", id, right);
+    }
+    Generated::new(input)
+}
+
+/// Reports of 5 to 8 levels: a third safe, a third safe without one bad level and a third with
+/// two bad levels, which no single removal saves.
+pub fn day02(rng: &mut Rng, reports: usize) -> Generated {
+    let mut input = String::new();
+    let (mut safe, mut dampened) = (0, 0);
+    for _ in 0..reports {
+        let bad = rng.below(3);
+        let len = rng.range(5..=8) as usize - bad;
+        let step = if rng.chance(0.5) { 1 } else { -1 };
+        let mut levels = vec![rng.range(30..=70) as i64];
+        while levels.len() < len {
+            levels.push(levels[levels.len() - 1] + step * rng.range(1..=3) as i64);
+        }
+        // Each bad level goes after a different good one: a repeat, or a jump of 10 and more.
+        let mut after: Vec<usize> = (0..len).collect();
+        rng.shuffle(&mut after);
+        after.truncate(bad);
+        after.sort_unstable_by(|a, b| b.cmp(a));
+        for at in after {
+            let level = match rng.chance(0.5) {
+                true => levels[at],
+                false => levels[at] + rng.range(10..=20) as i64,
+            };
+            levels.insert(at + 1, level);
+        }
+        match bad {
+            0 => safe += 1,
+            1 => dampened += 1,
+            _ => {}
+        }
+        let levels: Vec<String> = levels.iter().map(i64::to_string).collect();
+        input += &format!("{}\n", levels.join(" "));
+    }
+    Generated::answered(input, safe, safe + dampened)
+}
+
+/// Junk that can never spell an instruction: it has no `d` and no `l`.
+const JUNK: &[u8] = b"!@#$%^&*()[]{}<>,;:?'+- 0123456789whyseromu";
+
+/// Near misses of instructions, none of which a following junk character completes.
+const DECOYS: &[&str] = &["mul(1234,5)", "mul[3,7]", "mul(6,9!", "mul ( 2 , 4 )", "dont()", "do_not()"];
+
+/// Corrupted memory of `lines` lines with `instructions` `mul`, `do` and `don't` instructions
+/// scattered between junk and decoys.
+pub fn day03(rng: &mut Rng, lines: usize, instructions: usize) -> Generated {
+    let (mut all, mut enabled, mut on) = (0, 0, true);
+    let mut memory = vec![String::new(); lines];
+    for n in 0..instructions {
+        let line = &mut memory[n * lines / instructions];
+        for _ in 0..rng.below(8) {
+            line.push(*rng.pick(JUNK) as char);
+        }
+        if rng.chance(0.2) {
+            let decoy = rng.pick(DECOYS);
+            line.push_str(decoy);
+        }
+        match rng.below(10) {
+            0 => {
+                line.push_str("do()");
+                on = true;
+            }
+            1 => {
+                line.push_str("don't()");
+                on = false;
+            }
+            _ => {
+                let (a, b) = (rng.range(1..=999), rng.range(1..=999));
+                line.push_str(&format!("mul({},{})", a, b));
+                all += a * b;
+                if on {
+                    enabled += a * b;
+                }
+            }
+        }
+    }
+    let input = memory.iter().map(|line| format!("{}\n", line)).collect();
+    Generated::answered(input, all, enabled)
+}
+
+/// A word search of random `XMAS` letters.
+pub fn day04(rng: &mut Rng, height: usize, width: usize) -> Generated {
+    let input = (0..height)
+        .map(|_| (0..width).map(|_| *rng.pick(b"XMAS") as char).chain(['\n']).collect::<String>())
+        .collect();
+    Generated::new(input)
+}
+
+/// Rules ordering every pair of `pages` distinct pages (2 to 90), i.e. one consistent total
+/// order, and `updates` updates of an odd number of them, about half in order.
+pub fn day05(rng: &mut Rng, pages: usize, updates: usize) -> Generated {
+    let mut order: Vec<u64> = (10..=99).collect();
+    rng.shuffle(&mut order);
+    order.truncate(pages.clamp(2, 90));
+    let mut rules = vec![];
+    for (i, a) in order.iter().enumerate() {
+        for b in &order[i + 1..] {
+            rules.push(format!("{}|{}\n", a, b));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let mut input: String = rules.concat();
+    input.push('\n');
+    let (mut sorted, mut fixed) = (0, 0);
+    for _ in 0..updates {
+        let len = 2 * rng.below(order.len().min(23).div_ceil(2)) + 1;
+        let mut ranks: Vec<usize> = (0..order.len()).collect();
+        rng.shuffle(&mut ranks);
+        ranks.truncate(len);
+        let middle = {
+            let mut in_order = ranks.clone();
+            in_order.sort_unstable();
+            order[in_order[len / 2]]
+        };
+        if rng.chance(0.5) || len == 1 {
+            ranks.sort_unstable();
+            sorted += middle;
+        } else {
+            if ranks.is_sorted() {
+                ranks.reverse();
+            }
+            fixed += middle;
+        }
+        let update: Vec<String> = ranks.iter().map(|&rank| order[rank].to_string()).collect();
+        input += &format!("{}\n", update.join(","));
+    }
+    Generated::answered(input, sorted, fixed)
+}
+
+/// A lab with about `density` of its cells obstructed and a single guard `^`, who eventually
+/// walks out of it like in the real inputs.
+pub fn day06(rng: &mut Rng, height: usize, width: usize, density: f64) -> Generated {
+    loop {
+        let mut lab: Vec<Vec<bool>> =
+            (0..height).map(|_| (0..width).map(|_| rng.chance(density)).collect()).collect();
+        let start = (rng.below(height), rng.below(width));
+        // The guard stands on open ground.
+        lab[start.0][start.1] = false;
+        if !escapes(&lab, start) {
+            continue;
+        }
+        let mut input = String::new();
+        for (i, row) in lab.iter().enumerate() {
+            for (j, &obstacle) in row.iter().enumerate() {
+                input.push(match ((i, j) == start, obstacle) {
+                    (true, _) => '^',
+                    (_, true) => '#',
+                    _ => '.',
+                });
+            }
+            input.push('\n');
+        }
+        return Generated::new(input);
+    }
+}
+
+/// Whether a guard starting at `start` heading up ever leaves `lab`.
+fn escapes(lab: &[Vec<bool>], start: (usize, usize)) -> bool {
+    const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+    let (mut at, mut dir) = ((start.0 as isize, start.1 as isize), 0);
+    let mut seen = HashSet::new();
+    while seen.insert((at, dir)) {
+        let next = (at.0 + STEPS[dir].0, at.1 + STEPS[dir].1);
+        match lab.get(next.0 as usize).and_then(|row| row.get(next.1 as usize)) {
+            None => return true,
+            Some(true) => dir = (dir + 1) % 4,
+            Some(false) => at = next,
+        }
+    }
+    false
+}
+
+/// Calibration equations of 2 to 8 operands, mostly with a target some operators reach. The
+/// operands have at most 18 digits together, so no way of combining them overflows.
+pub fn day07(rng: &mut Rng, equations: usize) -> Generated {
+    let mut input = String::new();
+    for _ in 0..equations {
+        let count = rng.range(2..=8) as usize;
+        let mut operands = vec![];
+        let mut digits = 0;
+        while operands.len() < count {
+            let operand = rng.range(1..=if digits + 3 <= 18 { 999 } else { 9 });
+            digits += operand.to_string().len();
+            if digits > 18 {
+                break;
+            }
+            operands.push(operand);
+        }
+        let mut target = operands[1..].iter().fold(operands[0], |acc, &b| match rng.below(3) {
+            0 => acc + b,
+            1 => acc * b,
+            _ => format!("{}{}", acc, b).parse().unwrap(),
+        });
+        if rng.chance(0.3) {
+            target += 1;
+        }
+        let operands: Vec<String> = operands.iter().map(u64::to_string).collect();
+        input += &format!("{}: {}\n", target, operands.join(" "));
+    }
+    Generated::new(input)
+}
+
+/// Antenna frequencies in the order they are handed out.
+pub const FREQUENCIES: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// A map with `frequencies` frequencies of `antennae` antennae each, on distinct plots.
+pub fn day08(
+    rng: &mut Rng,
+    height: usize,
+    width: usize,
+    frequencies: usize,
+    antennae: usize,
+) -> Result<Generated> {
+    ensure!(frequencies <= FREQUENCIES.len(), "at most {} frequencies fit a map", FREQUENCIES.len());
+    ensure!(
+        frequencies * antennae <= height * width,
+        "{} antennae do not fit a {}x{} map",
+        frequencies * antennae,
+        height,
+        width
+    );
+    let mut plots: Vec<usize> = (0..height * width).collect();
+    rng.shuffle(&mut plots);
+    let mut map = vec!['.'; height * width];
+    for (n, freq) in FREQUENCIES.chars().take(frequencies).enumerate() {
+        for &plot in &plots[n * antennae..(n + 1) * antennae] {
+            map[plot] = freq;
+        }
+    }
+    let input = map.chunks(width).map(|row| row.iter().chain(&['\n']).collect::<String>()).collect();
+    Ok(Generated::new(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{find, REGISTRY};
+    use crate::lint;
+    use crate::runner::Part;
+
+    #[test]
+    fn reproducible() -> Result<()> {
+        assert_eq!(generate("06", 7, 20)?.input, generate("06", 7, 20)?.input);
+        assert_ne!(generate("06", 7, 20)?.input, generate("06", 8, 20)?.input);
+        // Pinned, so a change that reshuffles every shared seed does not go unnoticed.
+        assert_eq!(0xe220_a839_7b1d_cdaf, Rng::new(0).next_u64());
+        assert_eq!(1, generate("06", 3, 30)?.input.matches('^').count());
+        let map = day08(&mut Rng::new(3), 10, 10, 5, 3)?.input;
+        assert_eq!(5, map.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<HashSet<_>>().len());
+        assert_eq!(15, map.chars().filter(|c| c.is_ascii_alphanumeric()).count());
+        Ok(())
+    }
+
+    #[test]
+    fn every_day_lints_clean_and_gives_the_known_answers() -> Result<()> {
+        for puzzle in REGISTRY {
+            for (seed, size) in [(1, 1), (2, 7), (3, 40)] {
+                let generated = generate(puzzle.day(), seed, size)?;
+                let input = generated.input.as_bytes();
+                let context =
+                    format!("day {} seed {} size {}:\n{}", puzzle.day(), seed, size, generated.input);
+                assert_eq!(Vec::<lint::Problem>::new(), lint::lint(&puzzle.shape(), input), "{}", context);
+                for part in Part::BOTH {
+                    let answer = puzzle.solve(part, &mut &input[..])?;
+                    if let Some(expected) = generated.answers.get(part) {
+                        assert_eq!(expected, answer, "part {} of {}", part, context);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn every_generated_lab_lets_the_guard_out() -> Result<()> {
+        let day06 = find("06").unwrap();
+        let cases = (0..300).flat_map(|seed| [(seed, 5), (seed, 15), (seed, 30)]);
+        // Seed 765 at size 15 used to start the guard on an obstacle, which hid a loop.
+        for (seed, size) in cases.chain([(765, 15)]) {
+            let input = generate("06", seed, size)?.input;
+            day06
+                .solve(Part::One, &mut input.as_bytes())
+                .with_context(|| format!("seed {} size {}:\n{}", seed, size, input))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use crate::point::{Direction, Point};
+use crate::ParseError;
+use anyhow::*;
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+/// Row and column of a cell.
+pub type Pos = (usize, usize);
+
+/// A rectangular grid stored row by row in one `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows of equal length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        ensure!(rows.iter().all(|r| r.len() == width), "rows of a grid must have the same length");
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Reads one row per line, turning every character into a cell with `cell`. Blank lines are
+    /// skipped; an unknown character or a row of the wrong length is a [`ParseError`] of `day`.
+    pub fn parse<R, F>(reader: R, day: &'static str, mut cell: F) -> Result<Grid<T>>
+    where
+        R: BufRead,
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let mut row = 0;
+            for (at, c) in line.char_indices() {
+                let parsed = cell(c).ok_or_else(|| {
+                    ParseError::at(day, i, &line, &line[at..at + c.len_utf8()], "unknown cell")
+                })?;
+                cells.push(parsed);
+                row += 1;
+            }
+            let width = *width.get_or_insert(row);
+            ensure!(
+                row == width,
+                ParseError::at(day, i, &line, &line, format!("expected {} cells, found {}", width, row))
+            );
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (i, j): Pos) -> bool {
+        i < self.height && j < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The index of `point` if it lies inside the grid.
+    pub fn bounded(&self, point: Point) -> Option<Pos> {
+        point.to_pos().filter(|&pos| self.contains(pos))
+    }
+
+    /// The position `delta` away from `pos`, if it lies inside the grid.
+    pub fn offset(&self, pos: Pos, delta: Point) -> Option<Pos> {
+        self.bounded(Point::from(pos) + delta)
+    }
+
+    /// The neighbouring position of `pos` in direction `dir`, if it lies inside the grid.
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        self.offset(pos, dir.delta())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a zero size, an empty grid has no rows either way.
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |j| self.cells.iter().skip(j).step_by(self.width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(n, cell)| ((n / width, n % width), cell))
+    }
+
+    /// Positions of the cells for which `pred` holds, row by row.
+    pub fn positions<'a, F>(&'a self, pred: F) -> impl Iterator<Item = Pos> + 'a
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        self.cells().filter(move |(_, c)| pred(c)).map(|(pos, _)| pos)
+    }
+
+    /// The orthogonal neighbours inside the grid, clockwise from up.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// All neighbours inside the grid, diagonals included, clockwise from up.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours(pos, &Direction::ALL)
+    }
+
+    fn neighbours<'a>(&'a self, pos: Pos, dirs: &'static [Direction]) -> impl Iterator<Item = (Pos, &'a T)> {
+        dirs.iter()
+            .filter_map(move |&dir| self.step(pos, dir))
+            .map(|n| (n, &self[n]))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, height, width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Result<Grid<u32>> {
+        Grid::parse(text.as_bytes(), "00", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_and_access() -> Result<()> {
+        let grid = digits("123\n456\n\n")?;
+        assert_eq!((2, 3), (grid.height(), grid.width()));
+        assert_eq!(Some(&6), grid.get((1, 2)));
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(None, grid.bounded(Point::new(-1, 0)));
+        assert_eq!(Some((0, 2)), grid.offset((1, 1), Point::new(-1, 1)));
+        assert_eq!(None, grid.step((1, 2), Direction::Right));
+        assert_eq!(vec![&[1, 2, 3][..], &[4, 5, 6][..]], grid.rows().collect::<Vec<_>>());
+        let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], columns);
+        assert_eq!(vec![(1, 0)], grid.positions(|&d| d == 4).collect::<Vec<_>>());
+        assert_eq!(Some(&12), grid.map(|d| d * 2).get((1, 2)));
+        Ok(())
+    }
+
+    #[test]
+    fn neighbours_stay_inside() -> Result<()> {
+        let grid = digits("123\n456\n789\n")?;
+        let corner: Vec<u32> = grid.neighbours8((0, 0)).map(|(_, &d)| d).collect();
+        assert_eq!(vec![2, 5, 4], corner);
+        let centre: Vec<u32> = grid.neighbours4((1, 1)).map(|(_, &d)| d).collect();
+        assert_eq!(vec![2, 6, 8, 4], centre);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = digits("123\n4x6\n").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.text.as_str()));
+        let err = digits("123\n45\n").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!(2, err.line);
+        assert!(digits("").unwrap().is_empty());
+    }
+}
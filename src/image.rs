@@ -0,0 +1,238 @@
+use crate::animate::Stage;
+use crate::grid::Grid;
+use crate::render::{Colour, Glyph};
+use anyhow::*;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Image formats a drawn grid can be saved as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Png,
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Result<Format> {
+        match s.to_ascii_lowercase().as_str() {
+            "png" => Ok(Format::Png),
+            "ppm" => Ok(Format::Ppm),
+            "svg" => Ok(Format::Svg),
+            _ => bail!("image format must be png, ppm or svg, got `{}`", s),
+        }
+    }
+
+    /// The format named by the extension of `path`.
+    pub fn of(path: &Path) -> Result<Format> {
+        let extension = path.extension().and_then(|e| e.to_str());
+        Self::parse(extension.with_context(|| format!("{} has no image extension", path.display()))?)
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Png => "png",
+            Format::Ppm => "ppm",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+/// Cells drawn without any colour are painted in this one.
+const PLAIN: Colour = Colour::White;
+
+type Rgb = (u8, u8, u8);
+
+/// The colour a cell is filled with, and that of a smaller square inside it: a cell with both
+/// colours keeps the foreground, e.g. an antenna's frequency, visible inside its highlight.
+pub fn paint(glyph: &Glyph) -> (Rgb, Option<Rgb>) {
+    match (glyph.fg, glyph.bg) {
+        (Some(fg), Some(bg)) => (bg.rgb(), Some(fg.rgb())),
+        (fg, bg) => (bg.or(fg).unwrap_or(PLAIN).rgb(), None),
+    }
+}
+
+/// Writes `picture` as an image with every cell a `scale`×`scale` square.
+pub fn write<W: Write>(picture: &Grid<Glyph>, scale: usize, format: Format, mut out: W) -> Result<()> {
+    ensure!(scale > 0, "image scale must be at least 1");
+    let (width, height) = (picture.width() * scale, picture.height() * scale);
+    match format {
+        Format::Ppm => {
+            write!(out, "P6\n{} {}\n255\n", width, height)?;
+            out.write_all(&pixels(picture, scale))?;
+            out.flush()?;
+        }
+        Format::Png => {
+            let mut encoder = png::Encoder::new(out, u32::try_from(width)?, u32::try_from(height)?);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&pixels(picture, scale))?;
+            writer.finish()?;
+        }
+        Format::Svg => {
+            writeln!(
+                out,
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+                width,
+                height,
+                picture.width(),
+                picture.height()
+            )?;
+            for ((i, j), glyph) in picture.cells() {
+                let (fill, inset) = paint(glyph);
+                let (x, y) = (j as f64, i as f64);
+                let squares = [(0.0, 1.0, fill)].into_iter().chain(inset.map(|c| (0.25, 0.5, c)));
+                for (offset, size, (r, g, b)) in squares {
+                    writeln!(
+                        out,
+                        r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#{:02x}{:02x}{:02x}"/>"##,
+                        x + offset,
+                        y + offset,
+                        size,
+                        size,
+                        r,
+                        g,
+                        b
+                    )?;
+                }
+            }
+            writeln!(out, "</svg>")?;
+            out.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// RGB bytes of the scaled up picture, row by row. Insets take the middle half of a cell, once
+/// cells are big enough to show them.
+fn pixels(picture: &Grid<Glyph>, scale: usize) -> Vec<u8> {
+    let margin = scale / 4;
+    let inside = |n: usize| margin > 0 && (margin..scale - margin).contains(&n);
+    let mut data = Vec::with_capacity(picture.width() * picture.height() * scale * scale * 3);
+    for row in picture.rows() {
+        for y in 0..scale {
+            for glyph in row {
+                let (fill, inset) = paint(glyph);
+                for x in 0..scale {
+                    let (r, g, b) = match inset {
+                        Some(inset) if inside(x) && inside(y) => inset,
+                        _ => fill,
+                    };
+                    data.extend_from_slice(&[r, g, b]);
+                }
+            }
+        }
+    }
+    data
+}
+
+/// Saves `picture` to `path`, in the format its extension names.
+pub fn save(picture: &Grid<Glyph>, scale: usize, path: &Path) -> Result<()> {
+    let format = Format::of(path)?;
+    let file = File::create(path).with_context(|| format!("cannot create {}", path.display()))?;
+    write(picture, scale, format, BufWriter::new(file))
+}
+
+/// Saves every frame as a numbered image, e.g. `frames/06-2-00001.png`, ready to be turned into a
+/// video by other tools.
+pub struct Recorder {
+    dir: PathBuf,
+    prefix: String,
+    format: Format,
+    scale: usize,
+    frames: usize,
+}
+
+impl Recorder {
+    pub fn new(dir: &Path, prefix: &str, format: Format, scale: usize) -> Result<Recorder> {
+        fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            prefix: prefix.to_string(),
+            format,
+            scale,
+            frames: 0,
+        })
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+}
+
+impl Stage for Recorder {
+    fn frame(&mut self, picture: &Grid<Glyph>, _caption: &str) -> Result<()> {
+        self.frames += 1;
+        let name = format!("{}{:05}.{}", self.prefix, self.frames, self.format.extension());
+        save(picture, self.scale, &self.dir.join(name))
+    }
+}
+
+/// Keeps only the last frame, to save how the grid ended up.
+#[derive(Default)]
+pub struct Snapshot {
+    last: Option<Grid<Glyph>>,
+}
+
+impl Snapshot {
+    pub fn save(&self, scale: usize, path: &Path) -> Result<()> {
+        save(self.last.as_ref().context("no frame was drawn")?, scale, path)
+    }
+}
+
+impl Stage for Snapshot {
+    fn frame(&mut self, picture: &Grid<Glyph>, _caption: &str) -> Result<()> {
+        self.last = Some(picture.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture() -> Grid<Glyph> {
+        let cells = vec![vec![Glyph::new('#'), Glyph::new('.').fg(Colour::Grey).bg(Colour::Rgb(1, 2, 3))]];
+        Grid::from_rows(cells).unwrap()
+    }
+
+    #[test]
+    fn ppm() -> Result<()> {
+        let mut out = vec![];
+        write(&picture(), 1, Format::Ppm, &mut out)?;
+        assert_eq!(b"P6\n2 1\n255\n\xe5\xe5\xe5\x01\x02\x03".to_vec(), out);
+        Ok(())
+    }
+
+    #[test]
+    fn png_round_trip() -> Result<()> {
+        let mut out = vec![];
+        write(&picture(), 2, Format::Png, &mut out)?;
+        let mut reader = png::Decoder::new(&out[..]).read_info()?;
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data)?;
+        assert_eq!((4, 2), (info.width, info.height));
+        assert_eq!(pixels(&picture(), 2), data[..info.buffer_size()]);
+        assert_eq!(&[1, 2, 3], &data[21..24]);
+        // Big enough cells show the grey foreground inside.
+        let data = pixels(&picture(), 4);
+        let pixel = |x: usize, y: usize| &data[(y * 8 + x) * 3..(y * 8 + x) * 3 + 3];
+        assert_eq!((&[1, 2, 3][..], &[64, 64, 64][..]), (pixel(4, 0), pixel(5, 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn svg_and_formats() -> Result<()> {
+        let mut out = vec![];
+        write(&picture(), 10, Format::Svg, &mut out)?;
+        let svg = String::from_utf8(out)?;
+        assert!(svg.contains(r#"width="20" height="10" viewBox="0 0 2 1""#));
+        assert!(svg.contains(r##"<rect x="1" y="0" width="1" height="1" fill="#010203"/>"##));
+        assert!(svg.contains(r##"<rect x="1.25" y="0.25" width="0.5" height="0.5" fill="#404040"/>"##));
+        assert_eq!(Format::Svg, Format::of(Path::new("out/lab.SVG"))?);
+        assert!(Format::of(Path::new("lab")).is_err());
+        Ok(())
+    }
+}
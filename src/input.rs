@@ -0,0 +1,194 @@
+//! Splitting a whole puzzle input into typed pieces.
+use crate::parsers::parse_all;
+use crate::ParseError;
+use anyhow::*;
+use nom::IResult;
+use std::fmt::{self, Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// One block of lines from an input whose parts are separated by blank lines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Section {
+    day: &'static str,
+    /// 0 based index of the first line in the whole input.
+    first_line: usize,
+    lines: Vec<String>,
+}
+
+impl Section {
+    /// The lines with their 0 based index in the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(n, line)| (self.first_line + n, line.as_str()))
+    }
+
+    /// Parses every line with `parser`, which must consume all of it; see [`parse_all`].
+    pub fn parse_each<'a, T, P>(&'a self, mut parser: P, reason: &str) -> Result<Vec<T>, ParseError>
+    where
+        P: FnMut(&'a str) -> IResult<&'a str, T>,
+    {
+        self.lines()
+            .map(|(i, line)| parse_all(self.day, i, line, &mut parser, reason))
+            .collect()
+    }
+}
+
+/// Splits the input into exactly `N` sections at blank lines, for the day to parse each its own
+/// way. Blank lines around the sections are ignored and several in a row separate just once; any
+/// other number of sections is a [`ParseError`].
+pub fn sections<R: BufRead, const N: usize>(reader: R, day: &'static str) -> Result<[Section; N]> {
+    let mut sections: Vec<Section> = vec![];
+    let mut fresh = true;
+    let mut total = 0;
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        total = i + 1;
+        if line.is_empty() {
+            fresh = true;
+            continue;
+        }
+        if fresh {
+            fresh = false;
+            sections.push(Section {
+                day,
+                first_line: i,
+                lines: vec![],
+            });
+        }
+        sections.last_mut().unwrap().lines.push(line);
+    }
+    let reason = format!("expected {} blank line separated sections, found {}", N, sections.len());
+    match sections.get(N) {
+        Some(extra) => bail!(ParseError::new(day, extra.first_line + 1, 1, &extra.lines[0], reason)),
+        None => sections
+            .try_into()
+            .map_err(|_| ParseError::new(day, total + 1, 1, "", reason).into()),
+    }
+}
+
+/// Parses every line into a `T` with [`FromStr`], reporting the first line that does not parse
+/// with the reason `T` gives.
+pub fn parse_lines<T>(reader: impl BufRead, day: &'static str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut parsed = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let item = line.parse().map_err(|e| ParseError::at(day, i, &line, &line, format!("{}", e)))?;
+        parsed.push(item);
+    }
+    Ok(parsed)
+}
+
+/// How many numbers a row of [`parse_int_rows`] must have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Columns {
+    Any,
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl Columns {
+    pub(crate) fn allows(self, n: usize) -> bool {
+        match self {
+            Columns::Any => true,
+            Columns::Exactly(m) => n == m,
+            Columns::AtLeast(m) => n >= m,
+        }
+    }
+}
+
+impl Display for Columns {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Columns::Any => write!(f, "any number"),
+            Columns::Exactly(n) => write!(f, "{}", n),
+            Columns::AtLeast(n) => write!(f, "at least {}", n),
+        }
+    }
+}
+
+/// Parses every line into the whitespace separated integers on it, pointing at the first one
+/// that does not parse or at a row with the wrong number of `columns`.
+pub fn parse_int_rows<T>(
+    reader: impl BufRead,
+    day: &'static str,
+    columns: Columns,
+) -> Result<Vec<Vec<T>>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut rows = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let row = line
+            .split_whitespace()
+            .map(|n| {
+                n.parse()
+                    .map_err(|e| ParseError::at(day, i, &line, n, format!("expected an integer ({})", e)))
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+        ensure!(
+            columns.allows(row.len()),
+            ParseError::at(day, i, &line, &line, format!("expected {} columns, found {}", columns, row.len()))
+        );
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{comma_separated, unsigned};
+
+    #[test]
+    fn split_and_parse() -> Result<()> {
+        let [first, second] = sections("\n1,2\n3\n\n\n4,5\n\n".as_bytes(), "00")?;
+        assert_eq!(vec![(1, "1,2"), (2, "3")], first.lines().collect::<Vec<_>>());
+        let numbers = second.parse_each(comma_separated(unsigned::<u32>), "expected numbers")?;
+        assert_eq!(vec![vec![4, 5]], numbers);
+        let err = first.parse_each(unsigned::<u32>, "expected a number").unwrap_err();
+        assert_eq!((2, 2, ",2"), (err.line, err.column, err.text.as_str()));
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_count() {
+        let err = sections::<_, 2>("1\n\n2\n\n3\n".as_bytes(), "00").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((5, "3"), (err.line, err.text.as_str()));
+        assert_eq!("expected 2 blank line separated sections, found 3", err.reason);
+        let err = sections::<_, 2>("1\n2\n".as_bytes(), "00").unwrap_err();
+        assert_eq!(3, err.downcast::<ParseError>().unwrap().line);
+    }
+
+    #[test]
+    fn typed_lines() -> Result<()> {
+        assert_eq!(vec!['a', 'b'], parse_lines::<char>("a\nb\n".as_bytes(), "00")?);
+        let err = parse_lines::<char>("a\nbc\n".as_bytes(), "00").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((2, "bc"), (err.line, err.text.as_str()));
+        assert_eq!("too many characters in string", err.reason);
+        Ok(())
+    }
+
+    #[test]
+    fn int_rows() -> Result<()> {
+        let rows = parse_int_rows::<i64>("1 -2\n3   4\n".as_bytes(), "00", Columns::Exactly(2))?;
+        assert_eq!(vec![vec![1, -2], vec![3, 4]], rows);
+        let err = parse_int_rows::<u8>("1 2\n3 300\n".as_bytes(), "00", Columns::Any).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((2, 3, "300"), (err.line, err.column, err.text.as_str()));
+        let err = parse_int_rows::<i64>("1 2\n\n".as_bytes(), "00", Columns::AtLeast(1)).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((2, "expected at least 1 columns, found 0"), (err.line, err.reason.as_str()));
+        Ok(())
+    }
+}
@@ -1,3 +1,32 @@
+pub mod animate;
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod days;
+mod error;
+pub mod fetch;
+pub mod fixtures;
+pub mod fuzzing;
+pub mod generators;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod lint;
+pub mod memory;
+pub mod parallel;
+pub mod parsers;
+pub mod point;
+pub mod render;
+pub mod runner;
+pub mod scaffold;
+mod solution;
+pub mod submit;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use solution::Solution;
+
 pub fn start_day(day: &str) {
     println!("Advent of Code 2024 - Day {:0>2}", day);
 }
@@ -0,0 +1,245 @@
+//! Checks an input file against the shape of its day's puzzle input, so a damaged or misplaced
+//! file is pointed out before a solver trips over it.
+use crate::input::Columns;
+use crate::runner::Puzzle;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+/// What a day's input looks like.
+#[derive(Clone, Copy)]
+pub enum Shape {
+    /// Anything goes, e.g. free text.
+    Text,
+    /// A rectangular grid of the characters `glyph` accepts.
+    Grid(fn(char) -> bool),
+    /// Rows of whitespace separated integers.
+    IntRows(Columns),
+    /// Lines matching a regex, `what` says what they should look like.
+    Lines { pattern: &'static str, what: &'static str },
+    /// Parts separated by blank lines, each of its own shape.
+    Sections(&'static [Shape]),
+}
+
+impl Shape {
+    /// Whether input of this shape tells something about what day it is for.
+    fn is_specific(&self) -> bool {
+        !matches!(self, Shape::Text)
+    }
+}
+
+/// Something wrong with an input, at a 1 based line or with the whole file (line 0).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Problem {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.line {
+            0 => write!(f, "{}", self.message),
+            line => write!(f, "line {}: {}", line, self.message),
+        }
+    }
+}
+
+/// Problems of one kind beyond this many are only counted.
+const SHOWN_PER_KIND: usize = 3;
+
+#[derive(Default)]
+struct Linter {
+    problems: Vec<Problem>,
+    counts: HashMap<&'static str, usize>,
+}
+
+impl Linter {
+    /// Records a problem; `kind` groups the problems to count instead of showing.
+    fn report<S: Into<String>>(&mut self, line: usize, kind: &'static str, message: S) {
+        let count = self.counts.entry(kind).or_default();
+        *count += 1;
+        if *count <= SHOWN_PER_KIND {
+            let message = message.into();
+            self.problems.push(Problem { line, message });
+        }
+    }
+
+    fn finish(mut self) -> Vec<Problem> {
+        let mut more: Vec<_> = self
+            .counts
+            .into_iter()
+            .filter(|&(_, count)| count > SHOWN_PER_KIND)
+            .map(|(kind, count)| Problem {
+                line: 0,
+                message: format!("{} more of: {}", count - SHOWN_PER_KIND, kind),
+            })
+            .collect();
+        more.sort_by(|a, b| a.message.cmp(&b.message));
+        self.problems.append(&mut more);
+        self.problems
+    }
+}
+
+/// Lines of `text` with their 1 based numbers. Unlike [`str::lines`] a `\r` before the `\n`
+/// stays on the line.
+fn numbered(text: &str) -> Vec<(usize, &str)> {
+    text.split_terminator('\n').enumerate().map(|(i, line)| (i + 1, line)).collect()
+}
+
+/// Every problem found in `input` for a day whose input has the given `shape`.
+pub fn lint(shape: &Shape, input: &[u8]) -> Vec<Problem> {
+    let mut linter = Linter::default();
+    let Result::Ok(text) = std::str::from_utf8(input) else {
+        linter.report(0, "encoding", "not UTF-8 text");
+        return linter.finish();
+    };
+    if text.trim().is_empty() {
+        linter.report(0, "empty", "the input is empty");
+        return linter.finish();
+    }
+    let lines = numbered(text);
+    for &(n, line) in &lines {
+        if line.ends_with('\r') {
+            linter.report(n, "CRLF line ending", "CRLF line ending");
+        }
+    }
+    let text = text.replace("\r\n", "\n");
+    let lines = numbered(&text);
+    let first = lines.iter().position(|(_, line)| !line.is_empty()).unwrap_or(0);
+    let last = lines.iter().rposition(|(_, line)| !line.is_empty()).unwrap_or(0);
+    if first > 0 {
+        linter.report(1, "blank lines", "blank lines at the start");
+    }
+    if last + 1 < lines.len() {
+        linter.report(last + 2, "blank lines", "blank lines at the end");
+    }
+    check(shape, &lines[first..=last], &mut linter);
+    linter.finish()
+}
+
+fn check(shape: &Shape, lines: &[(usize, &str)], linter: &mut Linter) {
+    if let Shape::Sections(shapes) = shape {
+        let sections: Vec<_> = lines
+            .split(|(_, line)| line.is_empty())
+            .filter(|s| !s.is_empty())
+            .collect();
+        if sections.len() != shapes.len() {
+            let message = format!(
+                "expected {} blank line separated sections, found {}",
+                shapes.len(),
+                sections.len()
+            );
+            linter.report(0, "sections", message);
+        }
+        for (shape, section) in shapes.iter().zip(sections) {
+            check(shape, section, linter);
+        }
+        return;
+    }
+    let pattern = match shape {
+        Shape::Lines { pattern, .. } => Some(Regex::new(pattern).expect("invalid line pattern")),
+        _ => None,
+    };
+    let mut width = None;
+    for &(n, line) in lines {
+        if line.is_empty() {
+            linter.report(n, "blank line", "blank line");
+            continue;
+        }
+        if shape.is_specific() && line.ends_with(char::is_whitespace) {
+            linter.report(n, "trailing whitespace", "trailing whitespace");
+        }
+        match shape {
+            Shape::Text | Shape::Sections(_) => (),
+            Shape::Grid(glyph) => {
+                if let Some((column, c)) = line.chars().enumerate().find(|&(_, c)| !glyph(c)) {
+                    linter.report(n, "unknown glyph", format!("unknown glyph `{}` at column {}", c, column + 1));
+                }
+                let cells = line.chars().count();
+                let width = *width.get_or_insert(cells);
+                if cells != width {
+                    let message = format!("ragged row of {} cells, the first has {}", cells, width);
+                    linter.report(n, "ragged row", message);
+                }
+            }
+            Shape::IntRows(columns) => {
+                let numbers: Vec<_> = line.split_whitespace().collect();
+                if let Some(bad) = numbers.iter().find(|n| n.parse::<i64>().is_err()) {
+                    linter.report(n, "not an integer", format!("not an integer: `{}`", bad));
+                } else if !columns.allows(numbers.len()) {
+                    let message = format!("expected {} columns, found {}", columns, numbers.len());
+                    linter.report(n, "wrong number of columns", message);
+                }
+            }
+            Shape::Lines { what, .. } => {
+                if !pattern.as_ref().unwrap().is_match(line) {
+                    linter.report(n, what, format!("does not look like {}", what));
+                }
+            }
+        }
+    }
+}
+
+/// The days other than `day` whose shape `input` fits without a problem, when it does not fit
+/// its own; likely the file was saved under the wrong day.
+pub fn other_days(day: &str, input: &[u8], puzzles: &[&dyn Puzzle]) -> Vec<&'static str> {
+    puzzles
+        .iter()
+        .filter(|p| p.day() != day && p.shape().is_specific())
+        .filter(|p| lint(&p.shape(), input).is_empty())
+        .map(|p| p.day())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: Shape = Shape::Sections(&[
+        Shape::Lines { pattern: r"^\d+\|\d+$", what: "a rule" },
+        Shape::IntRows(Columns::AtLeast(1)),
+    ]);
+
+    fn lines_of(problems: Vec<Problem>) -> Vec<String> {
+        problems.iter().map(Problem::to_string).collect()
+    }
+
+    #[test]
+    fn layout() {
+        assert!(lint(&Shape::Text, b"anything\n").is_empty());
+        let problems = lint(&Shape::Text, b"\na\r\nb\r\nc\r\nd\r\ne\n\n\n");
+        let expected = [
+            "line 2: CRLF line ending",
+            "line 3: CRLF line ending",
+            "line 4: CRLF line ending",
+            "line 1: blank lines at the start",
+            "line 7: blank lines at the end",
+            "1 more of: CRLF line ending",
+        ];
+        assert_eq!(expected.to_vec(), lines_of(problems));
+        assert_eq!(vec!["not UTF-8 text"], lines_of(lint(&Shape::Text, b"\xff\n")));
+    }
+
+    #[test]
+    fn shapes() {
+        let grid = Shape::Grid(|c| c == '.' || c == '#');
+        assert_eq!(
+            vec!["line 2: unknown glyph `x` at column 2", "line 3: ragged row of 2 cells, the first has 3"],
+            lines_of(lint(&grid, b"#..\n.x.\n..\n"))
+        );
+        let rows = Shape::IntRows(Columns::Exactly(2));
+        assert_eq!(
+            vec![
+                "line 1: trailing whitespace",
+                "line 2: not an integer: `b`",
+                "line 3: expected 2 columns, found 1"
+            ],
+            lines_of(lint(&rows, b"1 2 \n3 b\n4\n"))
+        );
+        assert!(lint(&RULES, b"1|2\n3|4\n\n1 2\n").is_empty());
+        assert_eq!(
+            vec!["expected 2 blank line separated sections, found 1", "line 1: does not look like a rule"],
+            lines_of(lint(&RULES, b"1 2\n"))
+        );
+    }
+}
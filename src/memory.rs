@@ -0,0 +1,168 @@
+//! Heap accounting: a global allocator that counts what goes through it, and [`measure`] to
+//! read those counts around one piece of work. [`peak_resident`] asks the kernel instead, for the
+//! most memory resident at once.
+//!
+//! It is opt-in, the `aoc` binary installs [`Counting`] only when built with the
+//! `count-allocations` feature. The counters are shared by every thread, so a measurement is
+//! only meaningful while nothing else allocates at the same time.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting allocations, allocated bytes and the most bytes live at once.
+pub struct Counting;
+
+impl Counting {
+    fn allocated(size: usize) {
+        INSTALLED.store(true, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Counts as allocating the new size and freeing the old one, as a moving reallocation does.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let moved = System.realloc(ptr, layout, new_size);
+        if !moved.is_null() {
+            Self::allocated(new_size);
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        moved
+    }
+}
+
+/// Heap use of one piece of work.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    /// Bytes allocated in total, freed or not.
+    pub bytes: usize,
+    /// The most heap bytes live at once, over what was live before the work started. Only the
+    /// allocator's view: not resident memory, which also counts the stack, code and allocator
+    /// overhead.
+    pub peak_heap: usize,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak heap",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak_heap)
+        )
+    }
+}
+
+/// A byte count in the largest binary unit that keeps it at 1 or more.
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut size = self.0 as f64;
+        for unit in ["B", "KiB", "MiB"] {
+            if size < 1024.0 {
+                return match unit {
+                    "B" => write!(f, "{} B", self.0),
+                    _ => write!(f, "{:.1} {}", size, unit),
+                };
+            }
+            size /= 1024.0;
+        }
+        write!(f, "{:.1} GiB", size)
+    }
+}
+
+/// Runs `f`, with its heap use if [`Counting`] is the global allocator.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<Usage>) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let result = f();
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak_heap: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, Some(usage))
+}
+
+/// Runs `f`, with the most bytes the process had resident meanwhile: the heap, but also the stack,
+/// the code and whatever was resident before `f` started. Linux only, the kernel's high-water
+/// mark is reset before `f` and read after it; `None` where it cannot be.
+pub fn peak_resident<T, F: FnOnce() -> T>(f: F) -> (T, Option<usize>) {
+    // "5" resets the high-water mark to what is resident now, see proc(5).
+    let reset = fs::write("/proc/self/clear_refs", "5").is_ok();
+    let result = f();
+    (result, if reset { high_water_mark() } else { None })
+}
+
+/// `VmHWM` of `/proc/self/status`, in bytes.
+fn high_water_mark() -> Option<usize> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let kib = status.lines().find_map(|line| line.strip_prefix("VmHWM:"))?.trim().strip_suffix("kB")?;
+    Some(kib.trim().parse::<usize>().ok()? * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_reads_in_binary_units() {
+        let usage = Usage {
+            allocations: 3,
+            bytes: 5 * 1024 * 1024 + 512 * 1024,
+            peak_heap: 1000,
+        };
+        assert_eq!("3 allocations, 5.5 MiB allocated, 1000 B peak heap", usage.to_string());
+        assert_eq!("2.0 GiB", Bytes(2 << 30).to_string());
+        // The library's own tests run on the plain system allocator.
+        assert_eq!(None, measure(|| vec![0u8; 16]).1);
+    }
+
+    #[test]
+    fn peak_resident_covers_touched_memory() {
+        // Filled with ones, so every page is written and resident.
+        let (_, peak) = peak_resident(|| std::hint::black_box(vec![1u8; 64 << 20]).len());
+        if cfg!(target_os = "linux") {
+            assert!(peak.is_some_and(|peak| peak >= 64 << 20), "{:?}", peak);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Solving many days at once on a pool of threads, summed up in one table.
+use crate::answers::{AnswerStore, Status};
+use crate::fetch::InputProvider;
+use crate::runner::{InputSource, Part, Puzzle};
+use crate::Answer;
+use anyhow::*;
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// An answer and how it compares with the answer store.
+#[derive(Debug)]
+pub struct Solved {
+    pub answer: Answer,
+    pub status: Status,
+}
+
+/// The outcome of one part of one day.
+#[derive(Debug)]
+pub struct Row {
+    pub day: &'static str,
+    pub part: Part,
+    pub solved: Result<Solved>,
+    /// Time spent solving, parsing included.
+    pub time: Duration,
+}
+
+impl Row {
+    /// Whether the part failed or no longer gives its confirmed answer.
+    pub fn is_failure(&self) -> bool {
+        !matches!(&self.solved, Result::Ok(Solved { status: Status::Match | Status::New, .. }))
+    }
+}
+
+/// Solves `parts` of every puzzle on `threads` threads, a day at a time per thread so a day's
+/// input is read (or fetched) once. With `confirm` new answers are recorded in the store.
+/// The rows come back ordered by day and part.
+pub fn run_all(
+    puzzles: &[&dyn Puzzle],
+    parts: &[Part],
+    threads: usize,
+    inputs: &InputProvider,
+    store: &AnswerStore,
+    confirm: bool,
+) -> Vec<Row> {
+    let next = AtomicUsize::new(0);
+    let rows = Mutex::new(vec![]);
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, puzzles.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&puzzle) = puzzles.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let solved = run_day(puzzle, parts, inputs, store, confirm);
+                    rows.lock().unwrap().extend(solved);
+                }
+            });
+        }
+    });
+    let mut rows = rows.into_inner().unwrap();
+    rows.sort_by_key(|row| (row.day, u8::from(row.part)));
+    rows
+}
+
+fn run_day(
+    puzzle: &dyn Puzzle,
+    parts: &[Part],
+    inputs: &InputProvider,
+    store: &AnswerStore,
+    confirm: bool,
+) -> Vec<Row> {
+    let input = InputSource::Default.read(puzzle.day(), inputs);
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let solved = match &input {
+                Result::Ok(input) => puzzle.solve(part, &mut &input[..]),
+                Err(e) => Err(anyhow!("{:#}", e)),
+            };
+            let time = start.elapsed();
+            let solved = solved.and_then(|answer| {
+                let status = if confirm {
+                    store.confirm(puzzle.day(), part, &answer)?
+                } else {
+                    store.check(puzzle.day(), part, &answer)?
+                };
+                Ok(Solved { answer, status })
+            });
+            Row {
+                day: puzzle.day(),
+                part,
+                solved,
+                time,
+            }
+        })
+        .collect()
+}
+
+/// The rows as an aligned text table, followed by the errors of the failed parts.
+pub struct Table<'a>(pub &'a [Row]);
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let cells: Vec<[String; 5]> = self
+            .0
+            .iter()
+            .map(|row| {
+                let (answer, status) = match &row.solved {
+                    Result::Ok(solved) => (solved.answer.to_string(), solved.status.to_string()),
+                    Err(_) => ("-".to_string(), "ERROR".to_string()),
+                };
+                [row.day.to_string(), row.part.to_string(), answer, format!("{:.2?}", row.time), status]
+            })
+            .collect();
+        let header = ["Day", "Part", "Answer", "Time", "Check"].map(str::to_string);
+        let mut widths = header.clone().map(|h| h.chars().count());
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for row in std::iter::once(&header).chain(&cells) {
+            let line: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .enumerate()
+                // Times line up on the right, everything else on the left.
+                .map(|(n, (cell, width))| match n {
+                    3 => format!("{:>width$}", cell, width = width),
+                    _ => format!("{:<width$}", cell, width = width),
+                })
+                .collect();
+            writeln!(f, "{}", line.join("  ").trim_end())?;
+        }
+        for row in self.0 {
+            if let Err(e) = &row.solved {
+                writeln!(f, "day {} part {}: {:#}", row.day, row.part, e)?;
+            }
+        }
+        fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::input::parse_lines;
+    use crate::runner::Registered;
+    use crate::Solution;
+    use std::fs;
+    use std::io::BufRead;
+
+    /// Sums the lines of its input, part 2 of day 02 refuses to answer.
+    struct Sum<const N: u8>;
+
+    impl<const N: u8> Solution for Sum<N> {
+        const DAY: &'static str = ["01", "02", "03"][N as usize - 1];
+        type Input = Vec<u64>;
+        type Answer = u64;
+
+        fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+            parse_lines(reader, Self::DAY)
+        }
+
+        fn part1(input: &Self::Input) -> Result<u64> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<u64> {
+            ensure!(N != 2, "no part 2");
+            Ok(input.iter().product())
+        }
+    }
+
+    #[test]
+    fn runs_every_day_and_tabulates() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-parallel-{}", std::process::id()));
+        fs::create_dir_all(dir.join("input"))?;
+        fs::write(dir.join("input/01.txt"), "2\n3\n")?;
+        fs::write(dir.join("input/02.txt"), "4\n5\n")?;
+        fs::write(dir.join("input/03.txt"), "6\nx\n")?;
+        let inputs = InputProvider::new(&Config::default()).with_cache_dir(dir.join("input"));
+        let store = AnswerStore::new(dir.join("answers"));
+        store.confirm("01", Part::Two, &Answer::from(7u64))?;
+        let puzzles: [&dyn Puzzle; 3] =
+            [&Registered::<Sum<3>>::new(), &Registered::<Sum<1>>::new(), &Registered::<Sum<2>>::new()];
+
+        let rows = run_all(&puzzles, &Part::BOTH, 2, &inputs, &store, true);
+        let summary: Vec<_> =
+            rows.iter().map(|row| (row.day, u8::from(row.part), row.is_failure())).collect();
+        assert_eq!(
+            vec![
+                ("01", 1, false),
+                ("01", 2, true),
+                ("02", 1, false),
+                ("02", 2, true),
+                ("03", 1, true),
+                ("03", 2, true)
+            ],
+            summary
+        );
+        assert_eq!(Some("9"), store.load("02")?.get(Part::One));
+
+        let table = Table(&rows).to_string();
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].starts_with("Day  Part  Answer  "), "{}", lines[0]);
+        assert!(lines[1].starts_with("01   1     5       ") && lines[1].ends_with("NEW"), "{}", lines[1]);
+        assert!(lines[2].ends_with("MISMATCH (expected 7)"), "{}", lines[2]);
+        assert!(lines[4].starts_with("02   2     -") && lines[4].ends_with("ERROR"), "{}", lines[4]);
+        assert_eq!("day 02 part 2: no part 2", lines[7]);
+        assert!(lines[8].starts_with("day 03 part 1: "), "{}", lines[8]);
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! nom building blocks shared by the days' parsers, and [`parse_all`] to run them with errors
+//! that point into the input.
+use crate::ParseError;
+use nom::character::complete::{char, digit1, one_of, space0, space1};
+use nom::combinator::{map_res, opt, recognize};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, separated_pair, tuple};
+use nom::{IResult, Parser};
+use std::str::FromStr;
+
+/// Digits only, parsed into any integer type; too big a number for `T` is an error.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Digits with an optional leading `-` or `+`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// One or more `item`s separated by spaces or tabs.
+pub fn spaced<'a, T, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    F: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    separated_list1(space1, item)
+}
+
+/// One or more `item`s separated by commas, optionally followed by spaces.
+pub fn comma_separated<'a, T, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    F: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// A `key: value value ...` line, e.g. `190: 10 19`.
+pub fn key_values<'a, K, V, FK, FV>(
+    key: FK,
+    value: FV,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, Vec<V>)>
+where
+    FK: Parser<&'a str, K, nom::error::Error<&'a str>>,
+    FV: Parser<&'a str, V, nom::error::Error<&'a str>>,
+{
+    separated_pair(key, tuple((char(':'), space0)), spaced(value))
+}
+
+/// Runs `parser` over all of `input`, which starts at the 0 based line `line_idx` of the day's
+/// input. Where the parser fails, or stops short of the end, becomes a [`ParseError`] at that
+/// spot, with `reason` for a failure.
+pub fn parse_all<'a, T, P>(
+    day: &'static str,
+    line_idx: usize,
+    input: &'a str,
+    mut parser: P,
+    reason: &str,
+) -> Result<T, ParseError>
+where
+    P: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    let (at, reason) = match parser.parse(input) {
+        Ok(("", parsed)) => return Ok(parsed),
+        Ok((rest, _)) => (rest, "unexpected trailing text"),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => (e.input, reason),
+        Err(nom::Err::Incomplete(_)) => (input, reason),
+    };
+    Err(error_at(day, line_idx, input, at, reason))
+}
+
+/// A [`ParseError`] at `at`, a slice of the possibly multi-line `input`.
+fn error_at(day: &'static str, line_idx: usize, input: &str, at: &str, reason: &str) -> ParseError {
+    let offset = input.len() - at.len();
+    let before = &input[..offset];
+    let start = before.rfind('\n').map_or(0, |n| n + 1);
+    let line = input[start..].lines().next().unwrap_or("");
+    let fragment = at.lines().next().unwrap_or("");
+    ParseError::at(day, line_idx + before.matches('\n').count(), line, fragment, reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Ok(("", 42u8)), unsigned::<u8>("42"));
+        assert!(unsigned::<u8>("256").is_err());
+        assert!(unsigned::<u32>("-1").is_err());
+        assert_eq!(Ok((" 3", -12i64)), signed::<i64>("-12 3"));
+        assert_eq!(Ok(("", 7i32)), signed::<i32>("+7"));
+    }
+
+    #[test]
+    fn lists() {
+        assert_eq!(Ok(("", vec![1, 2, 3])), spaced(unsigned::<u32>)("1  2\t3"));
+        assert_eq!(Ok(("", vec![-1, 2])), comma_separated(signed::<i32>)("-1, 2"));
+        assert_eq!(Ok((",x", vec![4])), comma_separated(unsigned::<u32>)("4,x"));
+        let calibration = key_values(unsigned::<u64>, unsigned::<u64>)("190: 10 19");
+        assert_eq!(Ok(("", (190, vec![10, 19]))), calibration);
+    }
+
+    #[test]
+    fn errors_point_at_the_spot() {
+        let calibration = key_values(unsigned::<u64>, unsigned::<u64>);
+        let err = parse_all("07", 2, "190: 10 x", calibration, "expected `target: operands`").unwrap_err();
+        assert_eq!((3, 8, " x"), (err.line, err.column, err.text.as_str()));
+        assert_eq!("unexpected trailing text", err.reason);
+
+        let rows = separated_list1(char('\n'), comma_separated(unsigned::<u32>));
+        let err = parse_all("05", 4, "1,2\n3,,4\n5", rows, "expected pages").unwrap_err();
+        assert_eq!((6, 2, ",,4"), (err.line, err.column, err.text.as_str()));
+        let err = parse_all("01", 0, "x", signed::<i32>, "expected a number").unwrap_err();
+        assert_eq!("day 01, line 1, column 1: expected a number at `x`", err.to_string());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::grid::Pos;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed row/column pair: a location that may lie outside a grid, or the offset between two.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub i: i32,
+    pub j: i32,
+}
+
+impl Point {
+    pub const fn new(i: i32, j: i32) -> Point {
+        Self { i, j }
+    }
+
+    pub fn scale(self, k: i32) -> Point {
+        Self::new(self.i * k, self.j * k)
+    }
+
+    /// The grid index of this point, if neither coordinate is negative. Whether it is inside a
+    /// particular grid is up to [`Grid::bounded`](crate::grid::Grid::bounded).
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((usize::try_from(self.i).ok()?, usize::try_from(self.j).ok()?))
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((i, j): Pos) -> Point {
+        let coord = |n: usize| i32::try_from(n).expect("grid index does not fit a point");
+        Self::new(coord(i), coord(j))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Self::new(self.i + rhs.i, self.j + rhs.j)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Self::new(self.i - rhs.i, self.j - rhs.j)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Self::new(-self.i, -self.j)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, k: i32) -> Point {
+        self.scale(k)
+    }
+}
+
+/// The eight compass directions on a grid, where up is towards row 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Position in [`Direction::ALL`], i.e. in eighths of a turn clockwise from up.
+    pub fn eighths(self) -> usize {
+        self as usize
+    }
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Self::ALL[(self.eighths() + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::UpRight => Point::new(-1, 1),
+            Direction::Right => Point::new(0, 1),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(1, 0),
+            Direction::DownLeft => Point::new(1, -1),
+            Direction::Left => Point::new(0, -1),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '↑',
+            Direction::UpRight => '↗',
+            Direction::Right => '→',
+            Direction::DownRight => '↘',
+            Direction::Down => '↓',
+            Direction::DownLeft => '↙',
+            Direction::Left => '←',
+            Direction::UpLeft => '↖',
+        }
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Direction) -> Point {
+        self + dir.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (Point::new(3, 4), Point::new(1, 6));
+        assert_eq!(Point::new(4, 10), a + b);
+        assert_eq!(Point::new(2, -2), a - b);
+        assert_eq!(Point::new(5, 2), a + (a - b));
+        assert_eq!(Point::new(-6, -8), -a * 2);
+        assert_eq!(Point::new(2, 4), a + Direction::Up);
+        assert_eq!(Some((3, 4)), a.to_pos());
+        assert_eq!(None, Point::new(0, -1).to_pos());
+        assert_eq!(Point::new(7, 2), Point::from((7, 2)));
+    }
+
+    #[test]
+    fn rotation() {
+        let mut dir = Direction::Up;
+        for expected in [Direction::Right, Direction::Down, Direction::Left, Direction::Up] {
+            dir = dir.turn_right();
+            assert_eq!(expected, dir);
+        }
+        assert_eq!(Direction::UpLeft, Direction::DownLeft.turn_right());
+        assert_eq!(Direction::DownLeft, Direction::UpLeft.turn_left());
+        assert_eq!(Direction::Left, Direction::Right.reverse());
+        for dir in Direction::ALL {
+            assert_eq!(-dir.delta(), dir.reverse().delta());
+            assert_eq!(dir, dir.turn_left().turn_right());
+        }
+    }
+}
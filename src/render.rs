@@ -0,0 +1,245 @@
+use crate::grid::{Grid, Pos};
+use crate::point::Direction;
+use std::fmt::{self, Display, Formatter};
+use std::io::IsTerminal;
+
+/// The basic ANSI terminal colours, plus any RGB colour for terminals with true colour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+    Rgb(u8, u8, u8),
+}
+
+impl Colour {
+    /// A bright colour of the given hue, in degrees from red through green and blue.
+    pub fn hue(degrees: f64) -> Colour {
+        let h = degrees.rem_euclid(360.0) / 60.0;
+        let x = 1.0 - (h % 2.0 - 1.0).abs();
+        let (r, g, b) = match h as u8 {
+            0 => (1.0, x, 0.0),
+            1 => (x, 1.0, 0.0),
+            2 => (0.0, 1.0, x),
+            3 => (0.0, x, 1.0),
+            4 => (x, 0.0, 1.0),
+            _ => (1.0, 0.0, x),
+        };
+        // Mix in some white to soften it.
+        let channel = |c: f64| (255.0 * (0.25 + 0.75 * c)).round() as u8;
+        Colour::Rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// A colour for the `n`th kind of thing, e.g. an antenna frequency; nearby `n`s get hues
+    /// far apart.
+    pub fn distinct(n: usize) -> Colour {
+        // The golden angle spreads any number of hues about evenly.
+        Self::hue(n as f64 * 137.508)
+    }
+
+    /// Red, green and blue components, as the usual terminal palette draws the named colours.
+    pub fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Colour::Black => (0, 0, 0),
+            Colour::Red => (205, 49, 49),
+            Colour::Green => (13, 188, 121),
+            Colour::Yellow => (229, 229, 16),
+            Colour::Blue => (36, 114, 200),
+            Colour::Magenta => (188, 63, 188),
+            Colour::Cyan => (17, 168, 205),
+            Colour::White => (229, 229, 229),
+            Colour::Grey => (64, 64, 64),
+            Colour::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// The SGR parameters of this colour as a foreground (`base` 30) or background (`base` 40).
+    fn sgr(self, base: u8) -> String {
+        let code = |n: u8| (base + n).to_string();
+        match self {
+            Colour::Black => code(0),
+            Colour::Red => code(1),
+            Colour::Green => code(2),
+            Colour::Yellow => code(3),
+            Colour::Blue => code(4),
+            Colour::Magenta => code(5),
+            Colour::Cyan => code(6),
+            Colour::White => code(7),
+            Colour::Grey => code(60),
+            Colour::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+        }
+    }
+}
+
+/// How one cell is drawn: a character with optional foreground and background colours.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Glyph {
+    pub ch: char,
+    pub fg: Option<Colour>,
+    pub bg: Option<Colour>,
+}
+
+impl Glyph {
+    pub const fn new(ch: char) -> Glyph {
+        Self { ch, fg: None, bg: None }
+    }
+
+    pub const fn fg(self, colour: Colour) -> Glyph {
+        Self { fg: Some(colour), ..self }
+    }
+
+    pub const fn bg(self, colour: Colour) -> Glyph {
+        Self { bg: Some(colour), ..self }
+    }
+}
+
+impl Display for Glyph {
+    /// The character wrapped in ANSI escapes for its colours; the alternate form `{:#}` leaves
+    /// the colours out.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let codes: Vec<String> = [self.fg.map(|c| c.sgr(30)), self.bg.map(|c| c.sgr(40))]
+            .into_iter()
+            .flatten()
+            .collect();
+        if f.alternate() || codes.is_empty() {
+            write!(f, "{}", self.ch)
+        } else {
+            write!(f, "\x1b[{}m{}\x1b[0m", codes.join(";"), self.ch)
+        }
+    }
+}
+
+/// What an overlay does to the glyph underneath it.
+#[derive(Clone, Copy, Debug)]
+enum Overlay {
+    Replace(Glyph),
+    Background(Colour),
+}
+
+/// Draws a [`Grid`] through a cell→glyph mapping, with overlays on top.
+///
+/// Overlays are applied in the order they were added, so a later layer wins where two overlap.
+/// `Display` writes one line per row, coloured when stdout is a terminal unless
+/// [`Render::colour`] says otherwise.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: Box<dyn Fn(&T) -> Glyph + 'a>,
+    overlays: Vec<(Pos, Overlay)>,
+    colour: bool,
+}
+
+impl<'a, T> Render<'a, T> {
+    pub fn new<F: Fn(&T) -> Glyph + 'a>(grid: &'a Grid<T>, glyph: F) -> Render<'a, T> {
+        Self {
+            grid,
+            glyph: Box::new(glyph),
+            overlays: vec![],
+            colour: std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+        }
+    }
+
+    pub fn colour(mut self, colour: bool) -> Render<'a, T> {
+        self.colour = colour;
+        self
+    }
+
+    /// Draws `glyph` at `pos` in place of the cell.
+    pub fn marker(mut self, pos: Pos, glyph: Glyph) -> Render<'a, T> {
+        self.overlays.push((pos, Overlay::Replace(glyph)));
+        self
+    }
+
+    /// Keeps the cells at `positions` but paints their background.
+    pub fn highlight<I: IntoIterator<Item = Pos>>(mut self, positions: I, colour: Colour) -> Render<'a, T> {
+        self.overlays
+            .extend(positions.into_iter().map(|pos| (pos, Overlay::Background(colour))));
+        self
+    }
+
+    /// Draws a walk as arrows pointing the way it went.
+    pub fn path<I: IntoIterator<Item = (Pos, Direction)>>(mut self, steps: I, colour: Colour) -> Render<'a, T> {
+        self.overlays.extend(
+            steps
+                .into_iter()
+                .map(|(pos, dir)| (pos, Overlay::Replace(Glyph::new(dir.arrow()).fg(colour)))),
+        );
+        self
+    }
+
+    /// The glyph of every cell with the overlays applied. Overlays outside the grid are ignored.
+    pub fn glyphs(&self) -> Grid<Glyph> {
+        let mut glyphs = self.grid.map(&self.glyph);
+        for &(pos, overlay) in &self.overlays {
+            if let Some(glyph) = glyphs.get_mut(pos) {
+                match overlay {
+                    Overlay::Replace(over) => *glyph = over,
+                    Overlay::Background(colour) => glyph.bg = Some(colour),
+                }
+            }
+        }
+        glyphs
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.glyphs().rows() {
+            for glyph in row {
+                if self.colour {
+                    write!(f, "{}", glyph)?
+                } else {
+                    write!(f, "{:#}", glyph)?
+                }
+            }
+            writeln!(f)?
+        }
+        fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<bool> {
+        Grid::from_rows(vec![vec![false, true, false], vec![false, false, false]]).unwrap()
+    }
+
+    #[test]
+    fn overlays_in_order() {
+        let grid = grid();
+        let render = Render::new(&grid, |&wall| Glyph::new(if wall { '#' } else { '.' }))
+            .colour(false)
+            .path([((1, 0), Direction::Right), ((1, 1), Direction::Right)], Colour::Yellow)
+            .marker((1, 1), Glyph::new('O'))
+            .highlight([(0, 1), (5, 5)], Colour::Red);
+        assert_eq!(".#.\n→O.\n", render.to_string());
+        assert_eq!(Some(Colour::Red), render.glyphs()[(0, 1)].bg);
+    }
+
+    #[test]
+    fn ansi_colours() {
+        let grid = grid();
+        let render = Render::new(&grid, |&wall| Glyph::new(if wall { '#' } else { '.' }).fg(Colour::Grey))
+            .colour(true)
+            .marker((0, 0), Glyph::new('^').fg(Colour::Green).bg(Colour::Black));
+        let first = render.to_string().lines().next().unwrap().to_string();
+        assert_eq!("\x1b[32;40m^\x1b[0m\x1b[90m#\x1b[0m\x1b[90m.\x1b[0m", first);
+        let glyph = Glyph::new('x').fg(Colour::Rgb(1, 2, 3));
+        assert_eq!("\x1b[38;2;1;2;3mx\x1b[0m", glyph.to_string());
+    }
+
+    #[test]
+    fn hues() {
+        assert_eq!(Colour::Rgb(255, 64, 64), Colour::hue(0.0));
+        assert_eq!(Colour::Rgb(64, 64, 255), Colour::hue(240.0));
+        assert_eq!(Colour::hue(120.0), Colour::hue(-240.0));
+        assert_ne!(Colour::distinct(0), Colour::distinct(1));
+    }
+}
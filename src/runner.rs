@@ -0,0 +1,173 @@
+use crate::animate::Stage;
+use crate::fetch::InputProvider;
+use crate::lint::Shape;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{BufRead, Read};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn parse(s: &str) -> Result<Part> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("part must be 1 or 2, got `{}`", s),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Object safe face of a [`Solution`], so days with different input and answer types can sit
+/// in one registry.
+pub trait Puzzle: Sync {
+    fn day(&self) -> &'static str;
+
+    fn shape(&self) -> Shape;
+
+    /// Parses the input and answers the requested part.
+    fn solve(&self, part: Part, reader: &mut dyn BufRead) -> Result<Answer>;
+
+    /// Like [`Puzzle::solve`], showing the solution on `stage` on the way.
+    fn animate(&self, part: Part, reader: &mut dyn BufRead, stage: &mut dyn Stage) -> Result<Answer>;
+}
+
+/// Registry entry for the solution `S`.
+pub struct Registered<S>(PhantomData<fn() -> S>);
+
+impl<S> Registered<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Registered<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> Puzzle for Registered<S> {
+    fn day(&self) -> &'static str {
+        S::DAY
+    }
+
+    fn shape(&self) -> Shape {
+        S::SHAPE
+    }
+
+    fn solve(&self, part: Part, reader: &mut dyn BufRead) -> Result<Answer> {
+        let input = S::parse(reader)?;
+        let answer = match part {
+            Part::One => S::part1(&input)?,
+            Part::Two => S::part2(&input)?,
+        };
+        Ok(answer.into())
+    }
+
+    fn animate(&self, part: Part, reader: &mut dyn BufRead, stage: &mut dyn Stage) -> Result<Answer> {
+        let input = S::parse(reader)?;
+        Ok(S::animate(&input, part, stage)?.into())
+    }
+}
+
+/// Accepts `5`, `05` or `"05"` alike and returns the zero padded form used for file names.
+pub fn normalize_day(day: &str) -> Result<String> {
+    let n: u8 = day
+        .parse()
+        .with_context(|| format!("day must be a number, got `{}`", day))?;
+    ensure!((1..=25).contains(&n), "day must be between 1 and 25, got {}", n);
+    Ok(format!("{:0>2}", n))
+}
+
+/// Where a run takes its puzzle input from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// The day's own `input/NN.txt`, downloaded when missing.
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub const ENV: &'static str = "AOC_INPUT";
+
+    /// `-` means stdin, anything else a path.
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// The `--input` flag if given, else `AOC_INPUT`, else the day's own input.
+    pub fn resolve(flag: Option<&str>) -> InputSource {
+        match flag {
+            Some(arg) => Self::from_arg(arg),
+            None => match std::env::var(Self::ENV) {
+                Result::Ok(arg) if !arg.is_empty() => Self::from_arg(&arg),
+                _ => InputSource::Default,
+            },
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == InputSource::Default
+    }
+
+    /// Reads the whole input up front, so that both parts can be handed the same bytes.
+    pub fn read(&self, day: &str, inputs: &InputProvider) -> Result<Vec<u8>> {
+        match self {
+            InputSource::Default => Ok(fs::read(inputs.get(day)?)?),
+            InputSource::File(path) => {
+                fs::read(path).with_context(|| format!("cannot read input {}", path.display()))
+            }
+            InputSource::Stdin => {
+                let mut input = vec![];
+                std::io::stdin().lock().read_to_end(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_source_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("stress/06.txt")),
+            InputSource::resolve(Some("stress/06.txt"))
+        );
+    }
+}
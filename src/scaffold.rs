@@ -0,0 +1,77 @@
+use anyhow::*;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("days/NN.rs");
+
+const EXAMPLE_ANSWERS: &str = "\
+# Expected answers for example.txt, as strings
+# part1 = \"\"
+# part2 = \"\"
+";
+
+/// The day module generated from the `src/days/NN.rs` template.
+pub fn render(day: &str) -> String {
+    TEMPLATE
+        .replace(" // TODO: Fill the day", "")
+        .replace("NN", day)
+}
+
+/// Creates the source file, an empty input and an example case for `day` under `root`,
+/// returning the created paths. Nothing is written if any of them already exists.
+pub fn create(root: &Path, day: &str) -> Result<Vec<PathBuf>> {
+    let files = [
+        (root.join(format!("src/days/day{}.rs", day)), render(day)),
+        (root.join(format!("input/{}.txt", day)), String::new()),
+        (root.join(format!("examples/{}/example.txt", day)), String::new()),
+        (root.join(format!("examples/{}/example.toml", day)), EXAMPLE_ANSWERS.to_string()),
+    ];
+    if let Some((existing, _)) = files.iter().find(|(path, _)| path.exists()) {
+        bail!("{} already exists, refusing to overwrite it", existing.display());
+    }
+    for (path, content) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .with_context(|| format!("cannot create {}", path.display()))?
+            .write_all(content.as_bytes())?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_fills_the_day() {
+        let source = render("09");
+        assert!(source.contains("pub struct Day09;"));
+        assert!(source.contains("const DAY: &'static str = \"09\";"));
+        assert!(!source.contains("NN"));
+    }
+
+    #[test]
+    fn create_refuses_to_overwrite() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("input"))?;
+        fs::write(root.join("input/10.txt"), "mine")?;
+
+        assert_eq!(4, create(&root, "09")?.len());
+        let cases = crate::fixtures::load_dir(&root.join("examples/09"))?;
+        assert!(cases.len() == 1 && cases[0].is_pending());
+        assert!(create(&root, "09").is_err());
+        assert!(create(&root, "10").is_err());
+        assert_eq!("mine", fs::read_to_string(root.join("input/10.txt"))?);
+        assert!(!root.join("src/days/day10.rs").exists());
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
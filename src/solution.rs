@@ -0,0 +1,34 @@
+use crate::animate::Stage;
+use crate::lint::Shape;
+use crate::runner::Part;
+use anyhow::{bail, Result};
+use std::fmt::Debug;
+use std::io::BufRead;
+
+/// One day of the calendar: how to read the puzzle input and how to answer both parts of it.
+///
+/// Parsing is done once and both parts work on the parsed input, so callers (tests, benches,
+/// the runner) can reuse it.
+pub trait Solution {
+    /// Zero padded day number, the same one used for `input/NN.txt`.
+    const DAY: &'static str;
+
+    /// What the input looks like, for `aoc lint`.
+    const SHAPE: Shape = Shape::Text;
+
+    type Input;
+    /// Any integer type, or a `String`, see [`crate::Answer`].
+    type Answer: Into<crate::Answer> + Debug + PartialEq;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer>;
+
+    /// Answers `part` like [`Solution::part1`]/[`Solution::part2`] while showing how, frame by
+    /// frame, on `stage`. Only some days can.
+    fn animate(_input: &Self::Input, _part: Part, _stage: &mut dyn Stage) -> Result<Self::Answer> {
+        bail!("day {} has no animation", Self::DAY)
+    }
+}
@@ -0,0 +1,276 @@
+use crate::answers::ANSWERS_DIR;
+use crate::config::{Config, YEAR};
+use crate::runner::Part;
+use anyhow::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous attempt, with the seconds left to wait.
+    Wait(u64),
+    /// The part is already solved, or part 1 is not yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "RIGHT"),
+            Outcome::Wrong => write!(f, "WRONG"),
+            Outcome::TooHigh => write!(f, "WRONG (too high)"),
+            Outcome::TooLow => write!(f, "WRONG (too low)"),
+            Outcome::Wait(seconds) => write!(f, "WAIT ({}s left)", seconds),
+            Outcome::WrongLevel => write!(f, "WRONG LEVEL (already solved or locked)"),
+        }
+    }
+}
+
+/// Reads the outcome out of the page the site answers a submission with.
+pub fn parse_response(page: &str) -> Result<Outcome> {
+    if page.contains("That's the right answer") {
+        return Ok(Outcome::Right);
+    }
+    if page.contains("That's not the right answer") {
+        let outcome = if page.contains("too high") {
+            Outcome::TooHigh
+        } else if page.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        };
+        return Ok(outcome);
+    }
+    if page.contains("You gave an answer too recently") {
+        let re = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        let seconds = match re.captures(page) {
+            Some(caps) => {
+                let minutes: u64 = caps.get(1).map_or(Result::Ok(0), |m| m.as_str().parse())?;
+                minutes * 60 + caps[2].parse::<u64>()?
+            }
+            None => 60,
+        };
+        return Ok(Outcome::Wait(seconds));
+    }
+    if page.contains("You don't seem to be solving the right level") {
+        return Ok(Outcome::WrongLevel);
+    }
+    let excerpt: String = page.chars().take(200).collect();
+    bail!("unrecognised answer page: {}", excerpt)
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: String,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix time of the submission, in seconds.
+    pub at: u64,
+}
+
+/// Every submission made so far, kept in `answers/submissions.toml` so known wrong answers are
+/// never sent twice.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub submission: Vec<Submission>,
+}
+
+impl History {
+    pub fn default_path() -> PathBuf {
+        Path::new(ANSWERS_DIR).join("submissions.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<History> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let text = fs::read_to_string(path)?;
+        toml::from_str(&text).with_context(|| format!("malformed submission history {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: &str, part: Part, answer: &str, outcome: Outcome, at: u64) {
+        self.submission.push(Submission {
+            day: day.to_string(),
+            part: part.into(),
+            answer: answer.to_string(),
+            outcome,
+            at,
+        });
+    }
+
+    /// Fails with the reason when submitting `answer` at `now` is known to be pointless.
+    pub fn check(&self, day: &str, part: Part, answer: &str, now: u64) -> Result<()> {
+        let part: u8 = part.into();
+        let earlier: Vec<&Submission> = self
+            .submission
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect();
+        if let Some(right) = earlier.iter().find(|s| s.outcome == Outcome::Right) {
+            bail!("day {} part {} is already solved with {}", day, part, right.answer);
+        }
+        if let Some(wrong) = earlier.iter().find(|s| s.answer == answer && s.outcome.is_wrong()) {
+            bail!("{} was already submitted and is {}", answer, wrong.outcome);
+        }
+        if let Result::Ok(value) = answer.parse::<i128>() {
+            for s in &earlier {
+                let Result::Ok(known) = s.answer.parse::<i128>() else { continue };
+                let out_of_bounds = match s.outcome {
+                    Outcome::TooHigh => value >= known,
+                    Outcome::TooLow => value <= known,
+                    _ => false,
+                };
+                ensure!(!out_of_bounds, "{} is wrong, {} was already {}", answer, known, s.outcome);
+            }
+        }
+        let last_wait = self
+            .submission
+            .iter()
+            .filter_map(|s| match s.outcome {
+                Outcome::Wait(seconds) => Some(s.at + seconds),
+                _ => None,
+            })
+            .max();
+        if let Some(until) = last_wait.filter(|&until| until > now) {
+            bail!("the site asked to wait, try again in {}s", until - now);
+        }
+        Ok(())
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Posts answers to the configured site.
+pub struct SubmitClient {
+    base_url: String,
+    session: String,
+}
+
+impl SubmitClient {
+    pub fn new(config: &Config) -> Result<SubmitClient> {
+        Ok(Self {
+            base_url: config.base_url().to_string(),
+            session: config.session()?.to_string(),
+        })
+    }
+
+    pub fn post(&self, day: &str, part: Part, answer: &str) -> Result<Outcome> {
+        let n: u8 = day.parse()?;
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, n);
+        let level = part.to_string();
+        let page = match ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", level.as_str()), ("answer", answer)])
+        {
+            Result::Ok(response) => response.into_string()?,
+            Err(ureq::Error::Status(code, _)) => bail!("{} answered {}", url, code),
+            Err(e) => return Err(e).with_context(|| format!("cannot reach {}", url)),
+        };
+        parse_response(&page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answer_pages() -> Result<()> {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Outcome::Right,
+            parse_response(&page("That's the right answer!  You are one gold star closer."))?
+        );
+        assert_eq!(
+            Outcome::TooHigh,
+            parse_response(&page("That's not the right answer; your answer is too high."))?
+        );
+        assert_eq!(
+            Outcome::TooLow,
+            parse_response(&page("That's not the right answer; your answer is too low."))?
+        );
+        assert_eq!(Outcome::Wrong, parse_response(&page("That's not the right answer."))?);
+        assert_eq!(
+            Outcome::Wait(242),
+            parse_response(&page("You gave an answer too recently. You have 4m 2s left to wait."))?
+        );
+        assert_eq!(
+            Outcome::Wait(38),
+            parse_response(&page("You gave an answer too recently. You have 38s left to wait."))?
+        );
+        assert_eq!(
+            Outcome::WrongLevel,
+            parse_response(&page("You don't seem to be solving the right level."))?
+        );
+        assert!(parse_response("<html>Log in</html>").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() -> Result<()> {
+        let mut history = History::default();
+        history.record("06", Part::Two, "2000", Outcome::TooHigh, 100);
+        history.record("06", Part::Two, "1000", Outcome::TooLow, 200);
+        history.record("06", Part::Two, "1500", Outcome::Wrong, 300);
+
+        assert!(history.check("06", Part::Two, "1500", 1000).is_err());
+        assert!(history.check("06", Part::Two, "2500", 1000).is_err());
+        assert!(history.check("06", Part::Two, "999", 1000).is_err());
+        history.check("06", Part::Two, "1793", 1000)?;
+        history.check("06", Part::One, "1500", 1000)?;
+
+        history.record("06", Part::Two, "1793", Outcome::Right, 400);
+        assert!(history.check("06", Part::Two, "1794", 1000).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn honours_the_wait() -> Result<()> {
+        let mut history = History::default();
+        history.record("05", Part::One, "143", Outcome::Wait(60), 1000);
+        assert!(history.check("05", Part::One, "143", 1030).is_err());
+        history.check("05", Part::One, "143", 1060)?;
+        Ok(())
+    }
+
+    #[test]
+    fn history_round_trips() -> Result<()> {
+        let mut history = History::default();
+        history.record("05", Part::One, "143", Outcome::Wait(60), 1000);
+        history.record("05", Part::One, "143", Outcome::TooLow, 1100);
+        let read: History = toml::from_str(&toml::to_string(&history)?)?;
+        assert_eq!(history.submission, read.submission);
+        Ok(())
+    }
+}
@@ -0,0 +1,456 @@
+//! Differential tests: every day's solution against a slow but obviously right reference, on
+//! random inputs. A disagreement is shrunk to a minimal input and printed as puzzle text.
+
+use adv_code_2024::days::day01::Day01;
+use adv_code_2024::days::day02::Day02;
+use adv_code_2024::days::day03::Day03;
+use adv_code_2024::days::day04::Day04;
+use adv_code_2024::days::day05::Day05;
+use adv_code_2024::days::day06::Day06;
+use adv_code_2024::days::day07::Day07;
+use adv_code_2024::days::day08::Day08;
+use adv_code_2024::Solution;
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+/// Parses `input` and answers both parts with the production solution.
+fn solve<S: Solution>(input: &str) -> (S::Answer, S::Answer) {
+    let parsed = S::parse(input.as_bytes()).unwrap();
+    (S::part1(&parsed).unwrap(), S::part2(&parsed).unwrap())
+}
+
+mod day01 {
+    use super::*;
+
+    fn reference(pairs: &[(i32, i32)]) -> (i32, i32) {
+        let (mut left, mut right): (Vec<i32>, Vec<i32>) = pairs.iter().copied().unzip();
+        left.sort();
+        right.sort();
+        let distance = left.iter().zip(&right).map(|(a, b)| (a - b).abs()).sum();
+        let similarity = left.iter().map(|a| a * right.iter().filter(|&b| b == a).count() as i32).sum();
+        (distance, similarity)
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference(pairs in prop::collection::vec((-50..100i32, -50..100i32), 1..30)) {
+            let text: String = pairs.iter().map(|(a, b)| format!("{}   {}\n", a, b)).collect();
+            prop_assert_eq!(solve::<Day01>(&text), reference(&pairs), "lists:\n{}", text);
+        }
+    }
+}
+
+mod day02 {
+    use super::*;
+
+    fn safe(levels: &[i32]) -> bool {
+        let deltas: Vec<i32> = levels.windows(2).map(|w| w[1] - w[0]).collect();
+        deltas.iter().all(|d| (1..=3).contains(d)) || deltas.iter().all(|d| (-3..=-1).contains(d))
+    }
+
+    fn reference(reports: &[Vec<i32>]) -> (usize, usize) {
+        let dampened = |levels: &Vec<i32>| {
+            (0..levels.len()).any(|i| {
+                let mut removed = levels.clone();
+                removed.remove(i);
+                safe(&removed)
+            })
+        };
+        let safe_ones = reports.iter().filter(|levels| safe(levels)).count();
+        let dampened_ones = reports.iter().filter(|levels| safe(levels) || dampened(levels)).count();
+        (safe_ones, dampened_ones)
+    }
+
+    /// Levels moving by small steps, so that plenty of reports are safe or one level off it.
+    fn report() -> impl Strategy<Value = Vec<i32>> {
+        (20..60i32, prop::collection::vec(-4..=4i32, 0..8)).prop_map(|(start, steps)| {
+            steps.iter().scan(start, |level, step| {
+                *level += step;
+                Some(*level)
+            })
+            .fold(vec![start], |mut levels, level| {
+                levels.push(level);
+                levels
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference(reports in prop::collection::vec(report(), 1..20)) {
+            let text: String = reports
+                .iter()
+                .map(|levels| {
+                    let levels: Vec<String> = levels.iter().map(i32::to_string).collect();
+                    format!("{}\n", levels.join(" "))
+                })
+                .collect();
+            prop_assert_eq!(solve::<Day02>(&text), reference(&reports), "reports:\n{}", text);
+        }
+    }
+}
+
+mod day03 {
+    use super::*;
+
+    /// The product of a `mul(a,b)` instruction at the start of `text`, and its length.
+    fn mul(text: &str) -> Option<(i32, usize)> {
+        let rest = text.strip_prefix("mul(")?;
+        let number = |s: &str| {
+            let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+            (1..=3).contains(&digits).then(|| (s[..digits].parse::<i32>().unwrap(), digits))
+        };
+        let (a, a_len) = number(rest)?;
+        let rest = rest[a_len..].strip_prefix(',')?;
+        let (b, b_len) = number(rest)?;
+        rest[b_len..].starts_with(')').then_some((a * b, 4 + a_len + 1 + b_len + 1))
+    }
+
+    /// Scans the memory a byte at a time for instructions.
+    fn reference(memory: &str) -> (i32, i32) {
+        let (mut all, mut enabled, mut on) = (0, 0, true);
+        let mut at = 0;
+        while at < memory.len() {
+            let rest = &memory[at..];
+            if let Some((product, len)) = mul(rest) {
+                all += product;
+                if on {
+                    enabled += product;
+                }
+                at += len;
+                continue;
+            }
+            if rest.starts_with("do()") {
+                on = true;
+            } else if rest.starts_with("don't()") {
+                on = false;
+            }
+            at += 1;
+        }
+        (all, enabled)
+    }
+
+    /// Corrupted memory: bits of instructions, whole ones and junk, on a few lines.
+    fn memory() -> impl Strategy<Value = String> {
+        let piece = prop_oneof![
+            Just("mul(".to_string()),
+            (0..2000u32).prop_map(|n| n.to_string()),
+            (0..1000u32, 0..1000u32).prop_map(|(a, b)| format!("mul({},{})", a, b)),
+            prop::sample::select(vec![",", ")", "(", "do()", "don't()", "don't", "mul", "x", " ", "#!"])
+                .prop_map(str::to_string),
+        ];
+        let line = prop::collection::vec(piece, 1..30).prop_map(|pieces| pieces.concat());
+        prop::collection::vec(line, 1..4).prop_map(|lines| lines.join("\n") + "\n")
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference(memory in memory()) {
+            prop_assert_eq!(solve::<Day03>(&memory), reference(&memory), "memory:\n{}", memory);
+        }
+    }
+}
+
+mod day04 {
+    use super::*;
+
+    const DIRECTIONS: [(isize, isize); 8] =
+        [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+    fn reference(grid: &[Vec<char>]) -> (usize, usize) {
+        let at = |i: isize, j: isize| {
+            grid.get(usize::try_from(i).ok()?).and_then(|row| row.get(usize::try_from(j).ok()?)).copied()
+        };
+        let (mut xmas, mut x_mas) = (0, 0);
+        for i in 0..grid.len() as isize {
+            for j in 0..grid[0].len() as isize {
+                for (di, dj) in DIRECTIONS {
+                    let word: Option<String> = (0..4).map(|k| at(i + k * di, j + k * dj)).collect();
+                    if word.as_deref() == Some("XMAS") {
+                        xmas += 1;
+                    }
+                }
+                let diagonal = |d: isize| -> Option<String> {
+                    [at(i - 1, j - d), at(i, j), at(i + 1, j + d)].into_iter().collect()
+                };
+                let mas = |word: Option<String>| matches!(word.as_deref(), Some("MAS" | "SAM"));
+                if mas(diagonal(1)) && mas(diagonal(-1)) {
+                    x_mas += 1;
+                }
+            }
+        }
+        (xmas, x_mas)
+    }
+
+    fn grid() -> impl Strategy<Value = Vec<Vec<char>>> {
+        (1..=8usize, 1..=8usize).prop_flat_map(|(height, width)| {
+            let row = prop::collection::vec(prop::sample::select(vec!['X', 'M', 'A', 'S']), width);
+            prop::collection::vec(row, height)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference(grid in grid()) {
+            let text: String = grid.iter().map(|row| row.iter().chain(&['\n']).collect::<String>()).collect();
+            prop_assert_eq!(solve::<Day04>(&text), reference(&grid), "grid:\n{}", text);
+        }
+    }
+}
+
+mod day05 {
+    use super::*;
+    use std::cmp::Ordering;
+
+    /// Checks every pair of pages against the rules, and puts the wrong updates right by sorting
+    /// with the rules as the comparison.
+    fn reference(rules: &[(u32, u32)], updates: &[Vec<u32>]) -> (u64, u64) {
+        let before = |a: &u32, b: &u32| rules.contains(&(*a, *b));
+        let compare = |a: &u32, b: &u32| if before(a, b) { Ordering::Less } else { Ordering::Greater };
+        let (mut sorted, mut fixed) = (0, 0);
+        for update in updates {
+            let ordered =
+                (0..update.len()).all(|i| (i + 1..update.len()).all(|j| !before(&update[j], &update[i])));
+            if ordered {
+                sorted += u64::from(update[update.len() / 2]);
+            } else {
+                let mut update = update.clone();
+                update.sort_by(compare);
+                fixed += u64::from(update[update.len() / 2]);
+            }
+        }
+        (sorted, fixed)
+    }
+
+    /// A total order of a few pages, as rules between every two of them, and updates of distinct
+    /// pages, some in order and some shuffled.
+    fn manual() -> impl Strategy<Value = (Vec<(u32, u32)>, Vec<Vec<u32>>)> {
+        prop::sample::subsequence((10..100).collect::<Vec<u32>>(), 2..=12)
+            .prop_shuffle()
+            .prop_flat_map(|order| {
+                let rules: Vec<(u32, u32)> = (0..order.len())
+                    .flat_map(|i| (i + 1..order.len()).map(move |j| (i, j)))
+                    .map(|(i, j)| (order[i], order[j]))
+                    .collect();
+                let update = prop::sample::subsequence(order.clone(), 1..=order.len())
+                    .prop_flat_map(|pages| prop_oneof![Just(pages.clone()), Just(pages).prop_shuffle()]);
+                (Just(rules), prop::collection::vec(update, 1..10))
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference((rules, updates) in manual()) {
+            let rules_text: String = rules.iter().map(|(a, b)| format!("{}|{}\n", a, b)).collect();
+            let updates_text: String = updates
+                .iter()
+                .map(|update| {
+                    let pages: Vec<String> = update.iter().map(u32::to_string).collect();
+                    format!("{}\n", pages.join(","))
+                })
+                .collect();
+            let text = format!("{}\n{}", rules_text, updates_text);
+            prop_assert_eq!(solve::<Day05>(&text), reference(&rules, &updates), "manual:\n{}", text);
+        }
+    }
+}
+
+mod day07 {
+    use super::*;
+
+    /// Every value reachable by putting the operators between `operands`, left to right.
+    fn reachable(operands: &[u64], concatenate: bool) -> Vec<u64> {
+        let (&last, rest) = operands.split_last().unwrap();
+        if rest.is_empty() {
+            return vec![last];
+        }
+        let mut values = vec![];
+        for a in reachable(rest, concatenate) {
+            values.push(a + last);
+            values.push(a * last);
+            if concatenate {
+                values.push(format!("{}{}", a, last).parse().unwrap());
+            }
+        }
+        values
+    }
+
+    fn reference(equations: &[(u64, Vec<u64>)]) -> (u64, u64) {
+        let total = |concatenate| {
+            equations
+                .iter()
+                .filter(|(target, operands)| reachable(operands, concatenate).contains(target))
+                .map(|(target, _)| target)
+                .sum()
+        };
+        (total(false), total(true))
+    }
+
+    /// An equation whose target is sometimes reachable: one of the values the operands can make,
+    /// maybe nudged off by one, or any number at all.
+    fn equation() -> impl Strategy<Value = (u64, Vec<u64>)> {
+        prop::collection::vec(1..1000u64, 1..=6).prop_flat_map(|operands| {
+            let values = reachable(&operands, true);
+            let target = prop_oneof![
+                prop::sample::select(values.clone()),
+                prop::sample::select(values).prop_map(|v| v + 1),
+                1..100_000u64,
+            ];
+            (target, Just(operands))
+        })
+    }
+
+    fn text(equations: &[(u64, Vec<u64>)]) -> String {
+        equations
+            .iter()
+            .map(|(target, operands)| {
+                let operands: Vec<String> = operands.iter().map(u64::to_string).collect();
+                format!("{}: {}\n", target, operands.join(" "))
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference(equations in prop::collection::vec(equation(), 1..20)) {
+            let (part1, part2) = solve::<Day07>(&text(&equations));
+            let (expected1, expected2) = reference(&equations);
+            prop_assert_eq!((part1 as u64, part2 as u64), (expected1, expected2));
+        }
+    }
+}
+
+mod day06 {
+    use super::*;
+
+    const TURN: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+    /// Walks the guard from `start` heading up. The visited positions, or `None` if they never
+    /// leave the lab.
+    fn patrol(lab: &[Vec<bool>], start: (usize, usize)) -> Option<HashSet<(usize, usize)>> {
+        let (height, width) = (lab.len() as isize, lab[0].len() as isize);
+        let (mut i, mut j, mut dir) = (start.0 as isize, start.1 as isize, 0);
+        let mut seen = HashSet::new();
+        while seen.insert((i, j, dir)) {
+            let (ni, nj) = (i + TURN[dir].0, j + TURN[dir].1);
+            if !(0..height).contains(&ni) || !(0..width).contains(&nj) {
+                return Some(seen.into_iter().map(|(i, j, _)| (i as usize, j as usize)).collect());
+            }
+            if lab[ni as usize][nj as usize] {
+                dir = (dir + 1) % 4;
+            } else {
+                (i, j) = (ni, nj);
+            }
+        }
+        None
+    }
+
+    /// The visited positions, and how many single new obstructions (anywhere but the start)
+    /// trap the guard in a loop.
+    fn reference(lab: &[Vec<bool>], start: (usize, usize)) -> (usize, usize) {
+        let visited = patrol(lab, start).expect("the generated lab lets the guard out");
+        let mut loops = 0;
+        for (i, j) in visited.iter().copied().filter(|&pos| pos != start) {
+            let mut obstructed = lab.to_vec();
+            obstructed[i][j] = true;
+            if patrol(&obstructed, start).is_none() {
+                loops += 1;
+            }
+        }
+        (visited.len(), loops)
+    }
+
+    /// A lab of up to 10×10 with scattered obstacles and the guard on a free spot, which lets
+    /// the guard out; a lab that traps them has no part 1 answer.
+    fn lab() -> impl Strategy<Value = (Vec<Vec<bool>>, (usize, usize))> {
+        (1..=10usize, 1..=10usize)
+            .prop_flat_map(|(height, width)| {
+                let row = prop::collection::vec(prop::bool::weighted(0.2), width);
+                (prop::collection::vec(row, height), 0..height, 0..width)
+            })
+            .prop_map(|(mut lab, i, j)| {
+                lab[i][j] = false;
+                (lab, (i, j))
+            })
+            .prop_filter("the guard must get out", |(lab, start)| patrol(lab, *start).is_some())
+    }
+
+    fn text(lab: &[Vec<bool>], start: (usize, usize)) -> String {
+        let mut text = String::new();
+        for (i, row) in lab.iter().enumerate() {
+            for (j, &obstacle) in row.iter().enumerate() {
+                text.push(match (obstacle, (i, j) == start) {
+                    (_, true) => '^',
+                    (true, _) => '#',
+                    _ => '.',
+                });
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    proptest! {
+        // A solver that misses a loop walks the guard in circles for ever, so every case runs in
+        // a child process with a deadline.
+        #![proptest_config(ProptestConfig { fork: true, timeout: 5_000, ..ProptestConfig::default() })]
+
+        #[test]
+        fn agrees_with_reference((lab, start) in lab()) {
+            let text = text(&lab, start);
+            prop_assert_eq!(solve::<Day06>(&text), reference(&lab, start), "lab:\n{}", text);
+        }
+    }
+}
+
+mod day08 {
+    use super::*;
+
+    /// Tries every plot against every pair of antennae of the same frequency.
+    fn reference(map: &[Vec<char>]) -> (usize, usize) {
+        let antennae: Vec<(char, isize, isize)> = map
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, &c)| (c, i as isize, j as isize)))
+            .filter(|&(c, _, _)| c != '.')
+            .collect();
+        let pairs: Vec<_> = antennae
+            .iter()
+            .flat_map(|a| antennae.iter().map(move |b| (a, b)))
+            .filter(|(a, b)| a.0 == b.0 && a != b)
+            .collect();
+        let (mut first, mut resonant) = (0, 0);
+        for i in 0..map.len() as isize {
+            for j in 0..map[0].len() as isize {
+                // Twice as far from `b` as from `a`, on the far side of `a`.
+                if pairs.iter().any(|(a, b)| (i, j) == (2 * a.1 - b.1, 2 * a.2 - b.2)) {
+                    first += 1;
+                }
+                // A whole number of steps from `a` to `b` away from `a`.
+                let on_line = |(a, b): &(&(char, isize, isize), &(char, isize, isize))| {
+                    let (di, dj) = (b.1 - a.1, b.2 - a.2);
+                    (-20..=20).any(|k| (i, j) == (a.1 + k * di, a.2 + k * dj))
+                };
+                if pairs.iter().any(on_line) {
+                    resonant += 1;
+                }
+            }
+        }
+        (first, resonant)
+    }
+
+    fn map() -> impl Strategy<Value = Vec<Vec<char>>> {
+        (1..=10usize, 1..=10usize).prop_flat_map(|(height, width)| {
+            let plot = prop_oneof![8 => Just('.'), 1 => prop::sample::select(vec!['a', 'A', '0'])];
+            prop::collection::vec(prop::collection::vec(plot, width), height)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_reference(map in map()) {
+            let text: String = map.iter().map(|row| row.iter().chain(&['\n']).collect::<String>()).collect();
+            prop_assert_eq!(solve::<Day08>(&text), reference(&map), "map:\n{}", text);
+        }
+    }
+}
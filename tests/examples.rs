@@ -0,0 +1,37 @@
+use adv_code_2024::days::REGISTRY;
+use adv_code_2024::{fixtures, lint};
+
+#[test]
+fn every_example_case_passes() {
+    let mut failures = vec![];
+    for puzzle in REGISTRY {
+        // A freshly scaffolded day has a case still waiting for its answers, which is enough.
+        let cases = fixtures::load(puzzle.day()).unwrap();
+        assert!(!cases.is_empty(), "day {} has no example cases", puzzle.day());
+        for outcome in fixtures::run(*puzzle).unwrap().iter().filter(|o| !o.passed()) {
+            failures.push(format!(
+                "day {} {} part {}: expected {}, got {:?}",
+                puzzle.day(),
+                outcome.case,
+                outcome.part,
+                outcome.expected,
+                outcome.actual
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn every_example_input_lints_clean() {
+    let mut failures = vec![];
+    for puzzle in REGISTRY {
+        for case in fixtures::load(puzzle.day()).unwrap().iter().filter(|case| !case.is_pending()) {
+            let input = std::fs::read(&case.input).unwrap();
+            for problem in lint::lint(&puzzle.shape(), &input) {
+                failures.push(format!("{}: {}", case.input.display(), problem));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
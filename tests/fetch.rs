@@ -0,0 +1,67 @@
+mod common;
+
+use adv_code_2024::config::Config;
+use adv_code_2024::fetch::InputProvider;
+use common::{temp_dir, MockServer};
+use std::fs;
+
+fn provider(server: &MockServer, session: Option<&str>, cache: &str) -> InputProvider {
+    let config = Config {
+        session: session.map(str::to_string),
+        base_url: Some(server.base_url.clone()),
+    };
+    InputProvider::new(&config).with_cache_dir(temp_dir(cache))
+}
+
+#[test]
+fn fetches_once_and_caches() {
+    let server = MockServer::start(vec![(200, "1   2\n")]);
+    let provider = provider(&server, Some("cafe"), "fetch-cache");
+
+    let path = provider.get("05").unwrap();
+    assert_eq!("1   2\n", fs::read_to_string(&path).unwrap());
+    assert_eq!(path, provider.get("05").unwrap());
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("GET", requests[0].method);
+    assert_eq!("/2024/day/5/input", requests[0].path);
+    assert_eq!(Some("session=cafe"), requests[0].header("cookie"));
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn empty_input_is_fetched_again() {
+    let server = MockServer::start(vec![(200, "3   4\n")]);
+    let provider = provider(&server, Some("cafe"), "fetch-empty");
+    // What `aoc new` leaves behind.
+    fs::create_dir_all(provider.path("09").parent().unwrap()).unwrap();
+    fs::write(provider.path("09"), "").unwrap();
+    assert!(!provider.is_cached("09"));
+
+    let path = provider.get("09").unwrap();
+    assert_eq!("3   4\n", fs::read_to_string(&path).unwrap());
+    assert!(provider.is_cached("09"));
+    assert_eq!(1, server.requests().len());
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn rejected_session_is_not_cached() {
+    let server = MockServer::start(vec![(400, "Puzzle inputs differ by user.")]);
+    let provider = provider(&server, Some("stale"), "fetch-rejected");
+
+    let err = provider.get("06").unwrap_err();
+    assert!(format!("{:#}", err).contains("answered 400"), "{:#}", err);
+    assert!(!provider.path("06").exists());
+}
+
+#[test]
+fn missing_session_is_reported() {
+    let server = MockServer::start(vec![]);
+    let provider = provider(&server, None, "fetch-anonymous");
+
+    let err = provider.get("07").unwrap_err();
+    assert!(format!("{:#}", err).contains("AOC_SESSION"), "{:#}", err);
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,119 @@
+//! Hostile inputs: whatever the bytes, a day refuses them with a `ParseError` or answers (or
+//! fails) a part, and never panics or hangs. The same check as the fuzz targets in `fuzz/`, on a
+//! stable toolchain: the inputs that used to break a day, and random edits of the examples.
+
+use adv_code_2024::days::{day01, day02, day03, day04, day05, day06, day07, day08};
+use adv_code_2024::{fixtures, fuzzing};
+use proptest::prelude::*;
+
+/// Parses and answers an input, see [`fuzzing::solve`].
+type Solve = fn(&[u8]);
+
+const DAYS: [(&str, Solve); 8] = [
+    ("01", fuzzing::solve::<day01::Day01>),
+    ("02", fuzzing::solve::<day02::Day02>),
+    ("03", fuzzing::solve::<day03::Day03>),
+    ("04", fuzzing::solve::<day04::Day04>),
+    ("05", fuzzing::solve::<day05::Day05>),
+    ("06", fuzzing::solve::<day06::Day06>),
+    ("07", fuzzing::solve::<day07::Day07>),
+    ("08", fuzzing::solve::<day08::Day08>),
+];
+
+fn solve(day: &str, input: &[u8]) {
+    let (_, solve) = DAYS.iter().find(|(d, _)| *d == day).unwrap();
+    solve(input)
+}
+
+#[test]
+fn inputs_that_used_to_break_a_day() {
+    // Overflowing sums and differences.
+    solve("01", b"2147483647   -2147483648\n");
+    solve("01", b"2147483647   2147483647\n2147483647   2147483647\n");
+    solve("02", b"2147483647 -2147483648\n");
+    solve("03", "mul(999,999)".repeat(3000).as_bytes());
+    solve("07", "18446744073709551615: 18446744073709551615\n".repeat(2).as_bytes());
+    solve("07", b"5: 18446744073709551615 18446744073709551615 0 5\n");
+    // `\d` takes an Arabic-Indic digit that `parse` does not.
+    solve("03", "mul(\u{663},4)do()mul(1,\u{664})".as_bytes());
+    // Rules that go round in a cycle.
+    solve("05", b"1|2\n2|1\n\n1,2,3\n");
+    solve("05", b"1|2\n2|3\n3|1\n\n3,2,1\n");
+    solve("05", b"1|1\n\n1,1,1\n");
+    // Empty labs and maps.
+    for day in ["04", "06", "08"] {
+        solve(day, b"");
+        solve(day, b"\n\n");
+    }
+    // Two guards, a guard turning on the spot for ever and one walking a loop.
+    solve("06", b"^.^\n");
+    solve("06", b".#.\n#^#\n.#.\n");
+    solve("06", b".#...\n....#\n.^...\n#....\n...#.\n");
+    // Operands that undo every operator, doubling the search with each one.
+    solve("07", format!("19292: {}\n", ["1"; 60].join(" ")).as_bytes());
+    solve("07", format!("1000: {}\n", ["1"; 60].join(" ")).as_bytes());
+    // Not UTF-8 at all.
+    for (day, _) in DAYS {
+        solve(day, b"\xff\xfe\n");
+    }
+}
+
+/// An edit of a single byte: overwrite it, insert one before it or remove it.
+#[derive(Clone, Debug)]
+enum Edit {
+    Set(prop::sample::Index, u8),
+    Insert(prop::sample::Index, u8),
+    Remove(prop::sample::Index),
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    // Mostly the bytes puzzle inputs are made of, so edits get past the parsers.
+    let byte = prop_oneof![
+        prop::sample::select(b"0123456789 \n.#^,|:-".to_vec()),
+        any::<u8>(),
+    ];
+    prop_oneof![
+        (any::<prop::sample::Index>(), byte.clone()).prop_map(|(at, b)| Edit::Set(at, b)),
+        (any::<prop::sample::Index>(), byte).prop_map(|(at, b)| Edit::Insert(at, b)),
+        any::<prop::sample::Index>().prop_map(Edit::Remove),
+    ]
+}
+
+fn apply(mut input: Vec<u8>, edits: &[Edit]) -> Vec<u8> {
+    for edit in edits {
+        match *edit {
+            Edit::Set(ref at, b) if !input.is_empty() => {
+                let at = at.index(input.len());
+                input[at] = b;
+            }
+            Edit::Insert(ref at, b) => input.insert(at.index(input.len() + 1), b),
+            Edit::Remove(ref at) if !input.is_empty() => {
+                input.remove(at.index(input.len()));
+            }
+            _ => {}
+        }
+    }
+    input
+}
+
+proptest! {
+    // Each case runs in a child process with a deadline, so a hang fails rather than stalls.
+    #![proptest_config(ProptestConfig { fork: true, timeout: 10_000, ..ProptestConfig::default() })]
+
+    #[test]
+    fn edited_examples(
+        day in prop::sample::select(DAYS.map(|(day, _)| day).to_vec()),
+        edits in prop::collection::vec(edit(), 1..10),
+    ) {
+        let example = std::fs::read(&fixtures::load(day).unwrap()[0].input).unwrap();
+        solve(day, &apply(example, &edits));
+    }
+
+    #[test]
+    fn arbitrary_bytes(
+        day in prop::sample::select(DAYS.map(|(day, _)| day).to_vec()),
+        input in prop::collection::vec(any::<u8>(), 0..200),
+    ) {
+        solve(day, &input);
+    }
+}
@@ -0,0 +1,30 @@
+//! The counting allocator installed for real, in a test binary of its own so nothing else runs
+//! on it.
+use adv_code_2024::days::find;
+use adv_code_2024::memory::{self, Counting};
+use adv_code_2024::runner::Part;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn measures_allocations_and_peak_heap() {
+    let (kept, usage) = memory::measure(|| vec![1u8; 4096]);
+    let usage = usage.expect("the counting allocator is installed");
+    assert!(usage.allocations >= 1, "{:?}", usage);
+    assert!(usage.bytes >= 4096 && usage.peak_heap >= 4096, "{:?}", usage);
+
+    // A megabyte freed before the end still counts towards the peak heap, and only once.
+    let (_, usage) = memory::measure(|| {
+        drop(vec![1u8; 1 << 20]);
+        kept.len()
+    });
+    let usage = usage.unwrap();
+    assert!(usage.peak_heap >= 1 << 20 && usage.peak_heap < 2 << 20, "{:?}", usage);
+
+    let input = std::fs::read("examples/06/example.txt").unwrap();
+    let puzzle = find("06").unwrap();
+    let (answer, usage) = memory::measure(|| puzzle.solve(Part::Two, &mut &input[..]).unwrap());
+    assert_eq!("6", answer);
+    assert!(usage.unwrap().allocations > 0);
+}
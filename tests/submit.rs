@@ -0,0 +1,55 @@
+mod common;
+
+use adv_code_2024::config::Config;
+use adv_code_2024::runner::Part;
+use adv_code_2024::submit::{Outcome, SubmitClient};
+use common::MockServer;
+
+fn client(server: &MockServer) -> SubmitClient {
+    let config = Config {
+        session: Some("cafe".to_string()),
+        base_url: Some(server.base_url.clone()),
+    };
+    SubmitClient::new(&config).unwrap()
+}
+
+#[test]
+fn posts_the_answer_form() {
+    let server = MockServer::start(vec![(
+        200,
+        "<article><p>That's the right answer!  You are one gold star closer.</p></article>",
+    )]);
+
+    assert_eq!(Outcome::Right, client(&server).post("06", Part::Two, "1793").unwrap());
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("POST", requests[0].method);
+    assert_eq!("/2024/day/6/answer", requests[0].path);
+    assert_eq!(Some("session=cafe"), requests[0].header("cookie"));
+    assert_eq!(
+        Some("application/x-www-form-urlencoded"),
+        requests[0].header("content-type")
+    );
+    assert_eq!("level=2&answer=1793", requests[0].body);
+}
+
+#[test]
+fn reports_the_wait() {
+    let server = MockServer::start(vec![(
+        200,
+        "<article><p>You gave an answer too recently; you have to wait after submitting an answer \
+         before trying again.  You have 1m 5s left to wait.</p></article>",
+    )]);
+
+    assert_eq!(Outcome::Wait(65), client(&server).post("05", Part::One, "143").unwrap());
+}
+
+#[test]
+fn needs_a_session() {
+    let config = Config {
+        session: None,
+        base_url: Some("http://127.0.0.1:9".to_string()),
+    };
+    assert!(SubmitClient::new(&config).is_err());
+}